@@ -1,35 +1,39 @@
 //! Category management commands
 
-use crate::db;
+use super::format::resolve_game_format;
+use super::import::{commit_import, ImportTarget};
+use super::word::count_words_by_length;
+use crate::db::DbState;
 use crate::errors::AppError;
+use crate::export;
+use crate::game::format::{length_quotas, max_participants};
+use crate::game::GameSessionState;
+use crate::import;
 use crate::models::{
-    Category, CategoryExportData, CategoryExportInfo, ImportResult, ValidationResult,
+    Alphabet, Category, CategoryExportData, CategoryExportInfo, CategoryLanguage, ExportFile,
+    ExportPayload, GameFormat, ImportResult, Language, MergeStrategy, ValidationResult,
     WordCountByLength, WordExportInfo,
 };
+use crate::text;
+use rusqlite::{Connection, Row};
+use std::collections::HashMap;
+use tauri::State;
+
+const CATEGORY_COLUMNS: &str = "id, name, emoji, description, is_default, created_at, updated_at,
+     language, alphabet, custom_letters";
 
 /// Get all categories from database
 #[tauri::command]
-pub fn get_all_categories() -> Result<Vec<Category>, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_all_categories(db: State<'_, DbState>) -> Result<Vec<Category>, AppError> {
+    let conn = db.conn()?;
 
-    let mut stmt = conn.prepare(
-        "SELECT id, name, emoji, description, is_default, created_at, updated_at
-         FROM categories
-         ORDER BY is_default DESC, name ASC",
-    )?;
+    let mut stmt = conn.prepare_cached(&format!(
+        "SELECT {} FROM categories ORDER BY is_default DESC, name ASC",
+        CATEGORY_COLUMNS
+    ))?;
 
     let categories = stmt
-        .query_map([], |row| {
-            Ok(Category {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                emoji: row.get(2)?,
-                description: row.get(3)?,
-                is_default: row.get(4)?,
-                created_at: row.get(5)?,
-                updated_at: row.get(6)?,
-            })
-        })?
+        .query_map([], category_from_row)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(categories)
@@ -37,91 +41,281 @@ pub fn get_all_categories() -> Result<Vec<Category>, AppError> {
 
 /// Get a single category by ID
 #[tauri::command]
-pub fn get_category_by_id(id: i32) -> Result<Category, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_category_by_id(db: State<'_, DbState>, id: i32) -> Result<Category, AppError> {
+    let conn = db.conn()?;
+    fetch_category(&conn, id)
+}
 
+/// Load a category by ID using an existing connection
+pub(crate) fn fetch_category(conn: &Connection, id: i32) -> Result<Category, AppError> {
     let category = conn.query_row(
-        "SELECT id, name, emoji, description, is_default, created_at, updated_at
-         FROM categories
-         WHERE id = ?1",
+        &format!("SELECT {} FROM categories WHERE id = ?1", CATEGORY_COLUMNS),
         [id],
-        |row| {
-            Ok(Category {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                emoji: row.get(2)?,
-                description: row.get(3)?,
-                is_default: row.get(4)?,
-                created_at: row.get(5)?,
-                updated_at: row.get(6)?,
-            })
-        },
+        category_from_row,
     )?;
 
     Ok(category)
 }
 
+fn category_from_row(row: &Row<'_>) -> rusqlite::Result<Category> {
+    let language: String = row.get(7)?;
+    let alphabet: String = row.get(8)?;
+
+    Ok(Category {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        emoji: row.get(2)?,
+        description: row.get(3)?,
+        is_default: row.get(4)?,
+        created_at: row.get(5)?,
+        updated_at: row.get(6)?,
+        language: Language::from_db(&language),
+        alphabet: Alphabet::from_db(&alphabet),
+        custom_letters: row.get(9)?,
+    })
+}
+
+/// Check an alphabet choice, returning the letters to store for custom alphabets
+fn resolve_alphabet(
+    language: Language,
+    alphabet: Alphabet,
+    custom_letters: Option<&str>,
+) -> Result<Option<String>, AppError> {
+    if alphabet != Alphabet::Custom {
+        return Ok(None);
+    }
+
+    match custom_letters {
+        Some(letters) => text::parse_custom_letters(letters, language).map(Some),
+        None => Err(AppError::ValidationError(
+            "Özel alfabe için harfler girilmelidir".to_string(),
+        )),
+    }
+}
+
 /// Create a new category
+///
+/// The language defaults to Turkish and the alphabet to the language's
+/// alphabet; `custom_letters` is required for a custom alphabet.
 #[tauri::command]
 pub fn create_category(
+    db: State<'_, DbState>,
     name: String,
     emoji: String,
     description: Option<String>,
+    language: Option<CategoryLanguage>,
+) -> Result<Category, AppError> {
+    let conn = db.conn()?;
+
+    insert_category(
+        &conn,
+        &name,
+        &emoji,
+        description.as_deref(),
+        language.unwrap_or_default(),
+    )
+}
+
+/// Insert a category, see `create_category` for the defaults
+pub(crate) fn insert_category(
+    conn: &Connection,
+    name: &str,
+    emoji: &str,
+    description: Option<&str>,
+    options: CategoryLanguage,
 ) -> Result<Category, AppError> {
-    let conn = db::get_connection()?;
+    let language = options.language.unwrap_or_default();
+    let alphabet = options
+        .alphabet
+        .unwrap_or_else(|| language.default_alphabet());
+    let custom_letters = resolve_alphabet(language, alphabet, options.custom_letters.as_deref())?;
 
     // Insert the category
     conn.execute(
-        "INSERT INTO categories (name, emoji, description) VALUES (?1, ?2, ?3)",
-        (&name, &emoji, &description),
+        "INSERT INTO categories (name, emoji, description, language, alphabet, custom_letters)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        (
+            name,
+            emoji,
+            description,
+            language.as_str(),
+            alphabet.as_str(),
+            &custom_letters,
+        ),
     )?;
 
     let id = conn.last_insert_rowid() as i32;
 
     // Fetch and return the created category
-    get_category_by_id(id)
+    fetch_category(conn, id)
 }
 
 /// Update an existing category
+///
+/// The language and alphabet are kept when omitted. A new alphabet must
+/// accept all words already in the category.
 #[tauri::command]
 pub fn update_category(
+    db: State<'_, DbState>,
     id: i32,
     name: String,
     emoji: String,
     description: Option<String>,
+    language: Option<CategoryLanguage>,
 ) -> Result<Category, AppError> {
-    let conn = db::get_connection()?;
+    let conn = db.conn()?;
+    let options = language.unwrap_or_default();
 
     // Check if category exists
-    let _ = get_category_by_id(id)?;
+    let category = fetch_category(&conn, id)?;
+    let language = options.language.unwrap_or(category.language);
+
+    let (alphabet, custom_letters) = match options.alphabet {
+        Some(alphabet) => {
+            let custom_letters =
+                resolve_alphabet(language, alphabet, options.custom_letters.as_deref())?;
+            ensure_words_fit_alphabet(&conn, id, alphabet, custom_letters.as_deref())?;
+            (alphabet, custom_letters)
+        }
+        None => (category.alphabet, category.custom_letters),
+    };
+
+    // Words are compared with the language's collation, re-key them on a change
+    let word_keys = if language == category.language {
+        None
+    } else {
+        Some(collation_keys_for_language(&conn, id, language)?)
+    };
+
+    let tx = conn.unchecked_transaction()?;
 
     // Update the category
-    conn.execute(
+    tx.execute(
         "UPDATE categories
-         SET name = ?1, emoji = ?2, description = ?3, updated_at = CURRENT_TIMESTAMP
-         WHERE id = ?4",
-        (&name, &emoji, &description, id),
+         SET name = ?1, emoji = ?2, description = ?3, language = ?4, alphabet = ?5,
+             custom_letters = ?6, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?7",
+        (
+            &name,
+            &emoji,
+            &description,
+            language.as_str(),
+            alphabet.as_str(),
+            &custom_letters,
+            id,
+        ),
     )?;
 
+    if let Some(word_keys) = word_keys {
+        // The unique index is checked per row, so clear the old keys first
+        // (a word may take over the old key of another word)
+        tx.execute(
+            "UPDATE words SET normalized_word = '#' || id WHERE category_id = ?1",
+            [id],
+        )?;
+        let mut stmt = tx.prepare_cached("UPDATE words SET normalized_word = ?1 WHERE id = ?2")?;
+        for (word_id, key) in word_keys {
+            stmt.execute((&key, word_id))?;
+        }
+    }
+
+    tx.commit()?;
+
     // Fetch and return the updated category
-    get_category_by_id(id)
+    fetch_category(&conn, id)
+}
+
+/// Collation keys of the category's words under another language
+///
+/// Fails when two words would become duplicates (e.g. KIR and KİR are
+/// different in Turkish but the same in English).
+fn collation_keys_for_language(
+    conn: &Connection,
+    category_id: i32,
+    language: Language,
+) -> Result<Vec<(i32, String)>, AppError> {
+    let mut stmt =
+        conn.prepare_cached("SELECT id, word FROM words WHERE category_id = ?1 ORDER BY id")?;
+    let words = stmt
+        .query_map([category_id], |row| {
+            Ok((row.get::<_, i32>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut seen: HashMap<String, (i32, &str)> = HashMap::new();
+    let mut keys = Vec::with_capacity(words.len());
+    for (word_id, word) in &words {
+        let key = text::collation_key(word, language);
+        if let Some((other_id, other_word)) = seen.get(&key) {
+            return Err(AppError::DuplicateError(format!(
+                "Seçilen dilde {} (ID: {}) ve {} (ID: {}) aynı kelime sayılıyor",
+                other_word, other_id, word, word_id
+            )));
+        }
+        seen.insert(key.clone(), (*word_id, word));
+        keys.push((*word_id, key));
+    }
+
+    Ok(keys)
+}
+
+/// Reject an alphabet that some of the category's words do not fit
+fn ensure_words_fit_alphabet(
+    conn: &Connection,
+    category_id: i32,
+    alphabet: Alphabet,
+    custom_letters: Option<&str>,
+) -> Result<(), AppError> {
+    let mut stmt = conn.prepare_cached("SELECT word FROM words WHERE category_id = ?1")?;
+    let words = stmt
+        .query_map([category_id], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let misfits: Vec<&String> = words
+        .iter()
+        .filter(|word| text::check_letters(word, alphabet, custom_letters).is_err())
+        .collect();
+
+    match misfits.first() {
+        None => Ok(()),
+        Some(example) => Err(AppError::ValidationError(format!(
+            "Kategorideki {} kelime seçilen alfabeye uymuyor (örnek: {})",
+            misfits.len(),
+            example
+        ))),
+    }
 }
 
 /// Delete a category
 ///
-/// Prevents deletion of default category
+/// Prevents deletion of default category and of the category of the game
+/// in progress (the game could not be saved to history without it)
 #[tauri::command]
-pub fn delete_category(id: i32) -> Result<(), AppError> {
-    let conn = db::get_connection()?;
+pub fn delete_category(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    id: i32,
+) -> Result<(), AppError> {
+    let conn = db.conn()?;
 
     // Check if it's the default category
-    let category = get_category_by_id(id)?;
+    let category = fetch_category(&conn, id)?;
     if category.is_default {
         return Err(AppError::ValidationError(
             "Varsayılan kategori silinemez".to_string(),
         ));
     }
 
+    let db_path = db.path()?;
+    if game
+        .current()?
+        .is_some_and(|session| session.category_id == id && session.database_path == db_path)
+    {
+        return Err(AppError::ValidationError(
+            "Devam eden oyunun kategorisi silinemez".to_string(),
+        ));
+    }
+
     // Delete the category (CASCADE will delete related words)
     let rows_affected = conn.execute("DELETE FROM categories WHERE id = ?1", [id])?;
 
@@ -136,15 +330,31 @@ pub fn delete_category(id: i32) -> Result<(), AppError> {
 ///
 /// Returns detailed validation information including:
 /// - Total word count
-/// - Words per letter length
+/// - Words per letter length of the game format
 /// - Maximum supported players/teams
+///
+/// Uses the default game format when `format_id` is omitted.
 #[tauri::command]
-pub fn validate_category(id: i32) -> Result<ValidationResult, AppError> {
-    let conn = db::get_connection()?;
+pub fn validate_category(
+    db: State<'_, DbState>,
+    id: i32,
+    format_id: Option<i32>,
+) -> Result<ValidationResult, AppError> {
+    let conn = db.conn()?;
 
     // Check if category exists
-    let _ = get_category_by_id(id)?;
+    let _ = fetch_category(&conn, id)?;
+    let format = resolve_game_format(&conn, format_id)?;
+
+    category_validation(&conn, id, &format)
+}
 
+/// Playability of a category's current words under a game format
+pub(crate) fn category_validation(
+    conn: &Connection,
+    id: i32,
+    format: &GameFormat,
+) -> Result<ValidationResult, AppError> {
     // Get total word count
     let total_words: i32 = conn.query_row(
         "SELECT COUNT(*) FROM words WHERE category_id = ?1",
@@ -152,52 +362,49 @@ pub fn validate_category(id: i32) -> Result<ValidationResult, AppError> {
         |row| row.get(0),
     )?;
 
-    // Get word count by letter length (4-10)
-    let mut words_by_length = Vec::new();
-    let mut min_words_per_length = i32::MAX;
-
-    for letter_count in 4..=10 {
-        let count: i32 = conn.query_row(
-            "SELECT COUNT(*) FROM words WHERE category_id = ?1 AND letter_count = ?2",
-            [id, letter_count],
-            |row| row.get(0),
-        )?;
-
-        words_by_length.push(WordCountByLength {
+    // Get word count for every letter length of the format
+    let words_by_length: Vec<WordCountByLength> = count_words_by_length(conn, id, format)?
+        .into_iter()
+        .map(|(letter_count, count)| WordCountByLength {
             letter_count,
             count,
-        });
-
-        if count < min_words_per_length {
-            min_words_per_length = count;
-        }
-    }
+        })
+        .collect();
 
     // Calculate max supported players/teams
-    // Each player needs 2 words per letter length (14 total)
-    // So max players = min_words_per_length / 2
-    let max_players_multi = if min_words_per_length >= 2 {
-        min_words_per_length / 2
-    } else {
-        0
-    };
+    // Each player needs the format's quota of every letter length
+    let max_players_multi = max_participants(format, |letter_count| {
+        words_by_length
+            .iter()
+            .find(|entry| entry.letter_count == letter_count)
+            .map_or(0, |entry| entry.count)
+    });
 
     let max_teams = max_players_multi; // Same logic for teams
 
-    // Check if valid for single player (needs 14 words minimum)
-    let is_valid = total_words >= 14 && min_words_per_length >= 2;
+    // Playable for a single player if one set of words can be selected
+    let is_valid = max_players_multi >= 1;
 
     // Generate message
     let message = if !is_valid {
-        if total_words < 14 {
+        if total_words < format.total_words {
             format!(
-                "❌ Oynanamaz: En az 14 kelime gerekli (mevcut: {})",
-                total_words
+                "❌ Oynanamaz: En az {} kelime gerekli (mevcut: {})",
+                format.total_words, total_words
             )
         } else {
+            let missing: Vec<String> = length_quotas(format)
+                .into_iter()
+                .filter(|&(letter_count, quota)| {
+                    words_by_length
+                        .iter()
+                        .any(|entry| entry.letter_count == letter_count && entry.count < quota)
+                })
+                .map(|(letter_count, quota)| format!("{} harfli: {}", letter_count, quota))
+                .collect();
             format!(
-                "❌ Oynanamaz: Her harf uzunluğundan en az 2 kelime gerekli (en az: {})",
-                min_words_per_length
+                "❌ Oynanamaz: Yeterli kelime olmayan uzunluklar (gerekli) - {}",
+                missing.join(", ")
             )
         }
     } else if max_players_multi == 1 {
@@ -225,16 +432,40 @@ pub fn validate_category(id: i32) -> Result<ValidationResult, AppError> {
 
 /// Export category and its words as JSON
 ///
-/// Returns a JSON-serializable structure containing category info and all words
+/// Returns an export file (`crate::export`) holding the category info and
+/// all words, signed with `author` when given
 #[tauri::command]
-pub fn export_category_json(category_id: i32) -> Result<CategoryExportData, AppError> {
-    let conn = db::get_connection()?;
+pub fn export_category_json(
+    db: State<'_, DbState>,
+    category_id: i32,
+    author: Option<String>,
+) -> Result<ExportFile, AppError> {
+    let conn = db.conn()?;
+    category_export_file(&conn, category_id, author)
+}
+
+/// Export file of a category, using an existing connection
+pub(crate) fn category_export_file(
+    conn: &Connection,
+    category_id: i32,
+    author: Option<String>,
+) -> Result<ExportFile, AppError> {
+    let payload = ExportPayload::Category(export_category(conn, category_id)?);
+    let exported_at = conn.query_row("SELECT datetime('now')", [], |row| row.get(0))?;
+
+    export::seal(payload, Some(exported_at), author)
+}
 
+/// Category and its words in export form
+pub(crate) fn export_category(
+    conn: &Connection,
+    category_id: i32,
+) -> Result<CategoryExportData, AppError> {
     // Get category info
-    let category = get_category_by_id(category_id)?;
+    let category = fetch_category(conn, category_id)?;
 
     // Get all words for this category
-    let mut stmt = conn.prepare(
+    let mut stmt = conn.prepare_cached(
         "SELECT word, letter_count, hint
          FROM words
          WHERE category_id = ?1
@@ -256,6 +487,9 @@ pub fn export_category_json(category_id: i32) -> Result<CategoryExportData, AppE
             name: category.name,
             emoji: category.emoji,
             description: category.description,
+            language: Some(category.language),
+            alphabet: Some(category.alphabet),
+            custom_letters: category.custom_letters,
         },
         words,
     })
@@ -263,84 +497,32 @@ pub fn export_category_json(category_id: i32) -> Result<CategoryExportData, AppE
 
 /// Import words from JSON data into a category
 ///
-/// Validates the JSON structure and word format, then adds words to the category.
-/// Skips duplicate words (same word text) and returns statistics.
+/// Accepts export files of every version (see `crate::export`): older files
+/// are upgraded, files from a newer app and files whose checksum does not
+/// match are rejected. Validates the word format, then adds words to the category.
+/// Skips duplicate words (same word under the category language's collation,
+/// e.g. "kağıt" and "KÂĞIT" in Turkish) and returns statistics with the
+/// reason each skipped word was skipped.
+///
+/// Without `category_id` the category is taken from the file's `category`
+/// block: the category of the same name is used, or created when there is
+/// none (or always with `MergeStrategy::Rename`).
 #[tauri::command]
 pub fn import_category_json(
-    category_id: i32,
-    json_data: CategoryExportData,
+    db: State<'_, DbState>,
+    category_id: Option<i32>,
+    json_data: serde_json::Value,
+    strategy: Option<MergeStrategy>,
 ) -> Result<ImportResult, AppError> {
-    let conn = db::get_connection()?;
-
-    // Verify category exists
-    let _ = get_category_by_id(category_id)?;
+    let conn = db.conn()?;
 
-    let mut words_added = 0;
-    let mut words_skipped = 0;
-
-    // Process each word
-    for word_info in json_data.words {
-        // Validate word format (A-Z only, 4-10 letters)
-        let word_upper = word_info.word.to_uppercase();
-
-        // Check if valid characters (A-Z only)
-        if !word_upper.chars().all(|c| c.is_ascii_alphabetic()) {
-            words_skipped += 1;
-            continue;
-        }
-
-        // Check length
-        let letter_count = word_upper.len() as i32;
-        if !(4..=10).contains(&letter_count) {
-            words_skipped += 1;
-            continue;
-        }
-
-        // Validate letter_count matches actual length
-        if letter_count != word_info.letter_count {
-            words_skipped += 1;
-            continue;
-        }
-
-        // Check if word already exists in this category
-        let exists: i32 = conn.query_row(
-            "SELECT COUNT(*) FROM words WHERE category_id = ?1 AND word = ?2",
-            (category_id, word_upper.as_str()),
-            |row| row.get(0),
-        )?;
+    let json_data = export::open(json_data)?.payload.into_category()?;
 
-        if exists > 0 {
-            words_skipped += 1;
-            continue;
-        }
-
-        // Insert the word
-        match conn.execute(
-            "INSERT INTO words (category_id, word, letter_count, hint) VALUES (?1, ?2, ?3, ?4)",
-            (category_id, &word_upper, letter_count, &word_info.hint),
-        ) {
-            Ok(_) => words_added += 1,
-            Err(_) => words_skipped += 1,
-        }
-    }
-
-    let message = if words_added > 0 && words_skipped > 0 {
-        format!(
-            "{} kelime eklendi, {} kelime zaten vardı veya geçersizdi",
-            words_added, words_skipped
-        )
-    } else if words_added > 0 {
-        format!("{} kelime başarıyla eklendi", words_added)
-    } else {
-        format!(
-            "Hiç kelime eklenmedi, {} kelime zaten vardı veya geçersizdi",
-            words_skipped
-        )
+    let target = match category_id {
+        Some(id) => ImportTarget::Category(id),
+        None => ImportTarget::New(&json_data.category),
     };
+    let parsed = import::rows_from_export(&json_data);
 
-    Ok(ImportResult {
-        words_added,
-        words_skipped,
-        message,
-    })
+    commit_import(&conn, &target, parsed, strategy.unwrap_or_default())
 }
@@ -3,142 +3,181 @@
  * Task 31: Settings Screen - Data Management
  *
  * Provides commands for:
- * - Backup database to file
- * - Restore database from file
- * - Reset all data to defaults
+ * - Backup database to file (online backup + integrity check + manifest)
+ * - Restore database from file (validated, hot reload, automatic rollback)
+ * - Reset all data to defaults (or only history / custom categories / settings)
+ * - Automatic backups (scheduler, list, preview, delete)
  */
-use crate::db;
+use crate::db::auto_backup::{self, AutoBackupConfig, BackupFrequency};
+use crate::db::{backup, restore, seed, DbState};
 use crate::errors::AppError;
+use crate::game::GameSessionState;
+use crate::models::{BackupEntry, BackupManifest};
+use serde::Deserialize;
 use std::fs;
 use std::path::Path;
+use std::time::Duration;
+use tauri::{AppHandle, Manager, State};
+
+/// How often the scheduler checks whether a daily backup is due
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(15 * 60);
 
 /**
  * Backup database to a file
- * Exports the SQLite database to a user-specified location
+ * Copies the live database with SQLite's online backup API, verifies the
+ * copy with PRAGMA integrity_check and writes a manifest next to it
  *
  * @param backup_path - Full path where backup should be saved
  * @returns Success message or error
  */
 #[tauri::command]
-pub fn backup_database(backup_path: String) -> Result<String, AppError> {
-    // Get the current database path
-    let db_path = db::get_db_path()
-        .map_err(|e| AppError::DatabaseError(format!("Failed to get database path: {}", e)))?;
+pub fn backup_database(db: State<'_, DbState>, backup_path: String) -> Result<String, AppError> {
+    let conn = db.conn()?;
 
-    // Check if database exists
-    if !db_path.exists() {
-        return Err(AppError::DatabaseError(
-            "Database file not found".to_string(),
-        ));
-    }
+    let manifest = backup::backup_to(&conn, Path::new(&backup_path))?;
 
-    // Copy database file to backup location
-    fs::copy(&db_path, &backup_path)
-        .map_err(|e| AppError::DatabaseError(format!("Failed to backup database: {}", e)))?;
+    let total_rows: i64 = manifest.row_counts.iter().map(|count| count.rows).sum();
 
     Ok(format!(
-        "Database backed up successfully to: {}",
-        backup_path
+        "Database backed up successfully to: {} ({} rows, schema v{})",
+        backup_path, total_rows, manifest.schema_version
     ))
 }
 
+/**
+ * Read the manifest of a backup file
+ * Lets the UI show what a backup contains before restoring it
+ *
+ * @param backup_path - Full path to the backup file
+ * @returns Backup manifest (app version, schema version, row counts)
+ */
+#[tauri::command]
+pub fn get_backup_manifest(backup_path: String) -> Result<BackupManifest, AppError> {
+    backup::read_manifest(Path::new(&backup_path))
+}
+
 /**
  * Restore database from a backup file
  * Replaces current database with the backup
  *
+ * The backup is validated (SQLite header, integrity check, expected tables,
+ * schema version) and migrated before it is swapped in. The shared connection
+ * is reopened, so no restart is needed. If the swap fails the previous data
+ * is restored from `<name>_pre_restore.db` automatically. A game in progress
+ * is discarded, since its words and categories belong to the replaced data.
+ *
  * IMPORTANT: This will overwrite all current data!
  *
  * @param restore_path - Full path to the backup file
  * @returns Success message or error
  */
 #[tauri::command]
-pub fn restore_database(restore_path: String) -> Result<String, AppError> {
-    // Check if backup file exists
-    let backup_path = Path::new(&restore_path);
-    if !backup_path.exists() {
-        return Err(AppError::DatabaseError("Backup file not found".to_string()));
-    }
+pub fn restore_database(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    restore_path: String,
+) -> Result<String, AppError> {
+    let manifest = restore::restore_from(&db, Path::new(&restore_path))?;
+    game.take()?;
 
-    // Get the current database path
-    let db_path = db::get_db_path()
-        .map_err(|e| AppError::DatabaseError(format!("Failed to get database path: {}", e)))?;
+    let total_rows: i64 = manifest.row_counts.iter().map(|count| count.rows).sum();
 
-    // Create backup of current database before restoring (safety measure)
-    if db_path.exists() {
-        if let Some(parent) = db_path.parent() {
-            let safety_backup = parent.join("word-game_pre_restore.db");
-            fs::copy(&db_path, &safety_backup).ok(); // Don't fail if this doesn't work
-        }
-    }
-
-    // Copy backup file to database location
-    fs::copy(&backup_path, &db_path)
-        .map_err(|e| AppError::DatabaseError(format!("Failed to restore database: {}", e)))?;
+    Ok(format!(
+        "Database restored successfully ({} rows, schema v{}).",
+        total_rows, manifest.schema_version
+    ))
+}
 
-    Ok("Database restored successfully. Please restart the application.".to_string())
+/// What `reset_all_data` should reset
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetScope {
+    /// Everything: history, categories, words, settings, scoring presets and game formats (default data is re-seeded)
+    #[default]
+    All,
+    /// Game history only
+    History,
+    /// Non-default categories and their words
+    CustomCategories,
+    /// Settings only
+    Settings,
 }
 
 /**
- * Reset all data to defaults
- * Deletes all user data and re-initializes with default category
+ * Reset data to defaults
+ * Runs in a single transaction: either everything in the scope is reset or nothing is.
+ * A full reset re-runs the seeder, so the default category gets its 70 words back
+ * and settings match a fresh install. Resetting everything or the custom
+ * categories discards a game in progress, whose words may no longer exist.
  *
  * IMPORTANT: This cannot be undone!
  *
+ * @param scope - "all" (default), "history", "custom_categories" or "settings"
  * @returns Success message or error
  */
 #[tauri::command]
-pub fn reset_all_data() -> Result<String, AppError> {
-    let conn = db::get_connection()?;
-
-    // Delete all data from tables (in reverse dependency order)
-    conn.execute("DELETE FROM game_word_results", [])
-        .map_err(|e| {
-            AppError::DatabaseError(format!("Failed to delete game word results: {}", e))
-        })?;
-
-    conn.execute("DELETE FROM game_participants", [])
-        .map_err(|e| {
-            AppError::DatabaseError(format!("Failed to delete game participants: {}", e))
-        })?;
-
-    conn.execute("DELETE FROM game_history", [])
-        .map_err(|e| AppError::DatabaseError(format!("Failed to delete game history: {}", e)))?;
-
-    conn.execute("DELETE FROM words", [])
-        .map_err(|e| AppError::DatabaseError(format!("Failed to delete words: {}", e)))?;
-
-    conn.execute("DELETE FROM categories WHERE id != 1", [])
-        .map_err(|e| AppError::DatabaseError(format!("Failed to delete categories: {}", e)))?;
-
-    // Reset settings to defaults
-    conn.execute("DELETE FROM settings", [])
-        .map_err(|e| AppError::DatabaseError(format!("Failed to delete settings: {}", e)))?;
-
-    // Re-insert default settings
-    conn.execute(
-        "INSERT INTO settings (key, value) VALUES
-         ('sound_enabled', 'true'),
-         ('effects_volume', '100'),
-         ('animation_speed', 'normal'),
-         ('theme', 'dark'),
-         ('language', 'tr'),
-         ('show_hints', 'true'),
-         ('show_tutorial', 'true')",
-        [],
-    )
-    .map_err(|e| AppError::DatabaseError(format!("Failed to reset settings: {}", e)))?;
-
-    // Ensure default category exists with proper name
-    conn.execute(
-        "UPDATE categories SET name = 'Genel', emoji = '📚', description = 'Genel kelimeler' WHERE id = 1",
-        [],
-    )
-    .map_err(|e| AppError::DatabaseError(format!("Failed to reset default category: {}", e)))?;
-
-    Ok(
-        "All data has been reset to defaults. The default 'Genel' category is ready for new words."
-            .to_string(),
-    )
+pub fn reset_all_data(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    scope: Option<ResetScope>,
+) -> Result<String, AppError> {
+    let conn = db.conn()?;
+    let scope = scope.unwrap_or_default();
+
+    let tx = conn.unchecked_transaction()?;
+
+    let message = match scope {
+        ResetScope::All => {
+            // Delete all data from tables (in reverse dependency order)
+            tx.execute_batch(
+                "DELETE FROM game_word_results;
+                 DELETE FROM game_participants;
+                 DELETE FROM game_history;
+                 DELETE FROM word_set_words;
+                 DELETE FROM word_sets;
+                 DELETE FROM scoring_presets;
+                 DELETE FROM game_formats;
+                 DELETE FROM words;
+                 DELETE FROM word_merges;
+                 DELETE FROM categories;
+                 DELETE FROM settings;
+                 DELETE FROM sqlite_sequence;",
+            )?;
+
+            seed::seed_database_internal(&tx)?;
+
+            "All data has been reset to defaults. The default category and its 70 words have been restored."
+        }
+        ResetScope::History => {
+            tx.execute_batch(
+                "DELETE FROM game_word_results;
+                 DELETE FROM game_participants;
+                 DELETE FROM game_history;",
+            )?;
+
+            "Game history has been deleted."
+        }
+        ResetScope::CustomCategories => {
+            // Words are removed by ON DELETE CASCADE
+            tx.execute("DELETE FROM categories WHERE is_default = 0", [])?;
+
+            "Custom categories and their words have been deleted."
+        }
+        ResetScope::Settings => {
+            tx.execute("DELETE FROM settings", [])?;
+            seed::insert_default_settings(&tx)?;
+
+            "Settings have been reset to defaults."
+        }
+    };
+
+    tx.commit()?;
+
+    if matches!(scope, ResetScope::All | ResetScope::CustomCategories) {
+        game.take()?;
+    }
+
+    Ok(message.to_string())
 }
 
 /**
@@ -147,9 +186,8 @@ pub fn reset_all_data() -> Result<String, AppError> {
  * @returns Database file size in bytes, or error
  */
 #[tauri::command]
-pub fn get_database_size() -> Result<u64, AppError> {
-    let db_path = db::get_db_path()
-        .map_err(|e| AppError::DatabaseError(format!("Failed to get database path: {}", e)))?;
+pub fn get_database_size(db: State<'_, DbState>) -> Result<u64, AppError> {
+    let db_path = db.path()?;
 
     if !db_path.exists() {
         return Ok(0);
@@ -160,3 +198,119 @@ pub fn get_database_size() -> Result<u64, AppError> {
 
     Ok(metadata.len())
 }
+
+/**
+ * Start the background backup scheduler
+ * Takes a backup when the frequency is "daily" and none exists for today.
+ * Checked on startup and then every 15 minutes. Failures are recorded in
+ * the settings table.
+ *
+ * @param app - Application handle used to reach the shared database state
+ */
+pub fn spawn_backup_scheduler(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        let db = app.state::<DbState>();
+        if let Err(e) = run_due_backup(&db) {
+            record_backup_failure(&db, &e);
+        }
+        std::thread::sleep(SCHEDULER_INTERVAL);
+    });
+}
+
+/**
+ * Take the automatic backup configured for application exit
+ * A failure is recorded in the settings table for the next session to read.
+ *
+ * @param db - Shared database state
+ */
+pub fn run_exit_backup(db: &DbState) {
+    let result = (|| {
+        let db_path = db.path()?;
+        let conn = db.conn()?;
+
+        if AutoBackupConfig::load(&conn)?.frequency == BackupFrequency::OnExit {
+            auto_backup::create_backup(&conn, &db_path)?;
+        }
+        Ok::<_, AppError>(())
+    })();
+
+    if let Err(e) = result {
+        record_backup_failure(db, &e);
+    }
+}
+
+fn record_backup_failure(db: &DbState, error: &AppError) {
+    let recorded = db
+        .conn()
+        .and_then(|conn| auto_backup::record_failure(&conn, error));
+
+    // Nowhere left to report it when the database itself is unreachable
+    if let Err(e) = recorded {
+        eprintln!("Automatic backup failed: {} ({})", error, e);
+    }
+}
+
+fn run_due_backup(db: &DbState) -> Result<(), AppError> {
+    let db_path = db.path()?;
+    let conn = db.conn()?;
+
+    let config = AutoBackupConfig::load(&conn)?;
+    if config.frequency == BackupFrequency::Daily
+        && auto_backup::is_daily_backup_due(&conn, &db_path)?
+    {
+        auto_backup::create_backup(&conn, &db_path)?;
+    }
+
+    Ok(())
+}
+
+/**
+ * List automatic backups of the current database, newest first
+ *
+ * @returns Backup entries (file name, path, creation time, size)
+ */
+#[tauri::command]
+pub fn list_auto_backups(db: State<'_, DbState>) -> Result<Vec<BackupEntry>, AppError> {
+    auto_backup::list_backups(&db.path()?)
+}
+
+/**
+ * Take an automatic backup right now and apply the retention policy
+ *
+ * @returns The created backup entry
+ */
+#[tauri::command]
+pub fn create_auto_backup(db: State<'_, DbState>) -> Result<BackupEntry, AppError> {
+    let db_path = db.path()?;
+    let conn = db.conn()?;
+
+    auto_backup::create_backup(&conn, &db_path)
+}
+
+/**
+ * Preview an automatic backup before restoring it
+ *
+ * @param file_name - Backup file name as returned by list_auto_backups
+ * @returns Backup manifest
+ */
+#[tauri::command]
+pub fn preview_auto_backup(
+    db: State<'_, DbState>,
+    file_name: String,
+) -> Result<BackupManifest, AppError> {
+    let path = auto_backup::backup_path(&db.path()?, &file_name)?;
+
+    backup::read_manifest(&path)
+}
+
+/**
+ * Delete an automatic backup
+ *
+ * @param file_name - Backup file name as returned by list_auto_backups
+ */
+#[tauri::command]
+pub fn delete_auto_backup(db: State<'_, DbState>, file_name: String) -> Result<(), AppError> {
+    let path = auto_backup::backup_path(&db.path()?, &file_name)?;
+
+    auto_backup::delete_backup(&path)
+}
@@ -0,0 +1,131 @@
+//! File-path export and import commands
+//!
+//! The same exports and imports as `export_category_json`, `import_words`,
+//! `export_library` etc., but the file is written and read here instead of
+//! passing its contents through IPC. Paths come from the dialog plugin.
+
+use super::category::{category_export_file, export_category};
+use super::import::{commit_import, ImportTarget};
+use super::library::{import_categories, library_export};
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::export;
+use crate::import;
+use crate::models::{
+    ExportFormat, ImportOptions, ImportResult, LibraryImportResult, MergeStrategy,
+};
+use std::fs;
+use std::path::Path;
+use tauri::State;
+
+/// Export a category to a file
+///
+/// `format` is pretty-printed JSON (the export file, default) or CSV with
+/// the words and hints only.
+#[tauri::command]
+pub fn export_category_to_file(
+    db: State<'_, DbState>,
+    category_id: i32,
+    path: String,
+    format: Option<ExportFormat>,
+    author: Option<String>,
+) -> Result<String, AppError> {
+    let conn = db.conn()?;
+
+    let word_count = match format.unwrap_or_default() {
+        ExportFormat::Json => {
+            let file = category_export_file(&conn, category_id, author)?;
+            export::write_json(&file, Path::new(&path))?;
+            file.word_count
+        }
+        ExportFormat::Csv => {
+            let category = export_category(&conn, category_id)?;
+            export::write_csv(&category, Path::new(&path))?;
+            i32::try_from(category.words.len()).unwrap_or(i32::MAX)
+        }
+    };
+
+    Ok(format!("{} kelime dışa aktarıldı: {}", word_count, path))
+}
+
+/// Export every category to a library bundle file
+#[tauri::command]
+pub fn export_library_to_file(
+    db: State<'_, DbState>,
+    path: String,
+    author: Option<String>,
+) -> Result<String, AppError> {
+    let conn = db.conn()?;
+
+    let file = library_export(&conn, author)?;
+    export::write_json(&file, Path::new(&path))?;
+
+    let category_count = file.payload.into_categories().len();
+
+    Ok(format!(
+        "{} kategori, {} kelime dışa aktarıldı: {}",
+        category_count, file.word_count, path
+    ))
+}
+
+/// `import_words` reading the file from `path`
+#[tauri::command]
+pub fn import_words_from_file(
+    db: State<'_, DbState>,
+    category_id: i32,
+    path: String,
+    options: Option<ImportOptions>,
+    strategy: Option<MergeStrategy>,
+) -> Result<ImportResult, AppError> {
+    let data = fs::read(&path)?;
+    let parsed = import::parse_file(&path, &data, &options.unwrap_or_default())?;
+
+    let conn = db.conn()?;
+    commit_import(
+        &conn,
+        &ImportTarget::Category(category_id),
+        parsed,
+        strategy.unwrap_or_default(),
+    )
+}
+
+/// `import_category_json` reading the export file from `path`
+#[tauri::command]
+pub fn import_category_from_file(
+    db: State<'_, DbState>,
+    category_id: Option<i32>,
+    path: String,
+    strategy: Option<MergeStrategy>,
+) -> Result<ImportResult, AppError> {
+    let data = export::read_file(Path::new(&path))?
+        .payload
+        .into_category()?;
+
+    let target = match category_id {
+        Some(id) => ImportTarget::Category(id),
+        None => ImportTarget::New(&data.category),
+    };
+
+    let conn = db.conn()?;
+    commit_import(
+        &conn,
+        &target,
+        import::rows_from_export(&data),
+        strategy.unwrap_or_default(),
+    )
+}
+
+/// `import_library` reading the bundle from `path`
+#[tauri::command]
+pub fn import_library_from_file(
+    db: State<'_, DbState>,
+    path: String,
+    strategy: Option<MergeStrategy>,
+) -> Result<LibraryImportResult, AppError> {
+    let bundle = export::read_file(Path::new(&path))?
+        .payload
+        .into_categories();
+
+    let conn = db.conn()?;
+    import_categories(&conn, &bundle, strategy.unwrap_or_default())
+}
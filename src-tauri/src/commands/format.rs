@@ -0,0 +1,255 @@
+//! Game format commands
+//!
+//! Formats are named word-length ladders stored in the `game_formats` table.
+//! Built-in formats ("Standart", "İlkokul", "Uzun") cannot be changed or
+//! deleted, custom formats only while no saved or running game and no word
+//! set uses them.
+//! Word selection and category validation take an optional format ID
+//! and fall back to the default format.
+
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::game::format::validate_format;
+use crate::game::GameSessionState;
+use crate::models::{GameFormat, GameFormatPreset, WordOrder};
+use rusqlite::{params, Connection, Row};
+use tauri::State;
+
+const FORMAT_COLUMNS: &str = "id, name, min_length, max_length, words_per_length,
+     total_words, word_order, is_default, created_at";
+
+/// Get all game formats (built-in formats first)
+#[tauri::command]
+pub fn get_game_formats(db: State<'_, DbState>) -> Result<Vec<GameFormatPreset>, AppError> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn.prepare_cached(&format!(
+        "SELECT {} FROM game_formats ORDER BY is_default DESC, id ASC",
+        FORMAT_COLUMNS
+    ))?;
+
+    let formats = stmt
+        .query_map([], format_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(formats)
+}
+
+/// Create a custom game format
+#[tauri::command]
+pub fn create_game_format(
+    db: State<'_, DbState>,
+    name: String,
+    format: GameFormat,
+) -> Result<GameFormatPreset, AppError> {
+    let name = validate_format_name(&name)?;
+    validate_format(&format)?;
+
+    let conn = db.conn()?;
+    conn.execute(
+        "INSERT INTO game_formats
+         (name, min_length, max_length, words_per_length, total_words, word_order, is_default)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0)",
+        params![
+            name,
+            format.min_length,
+            format.max_length,
+            format.words_per_length,
+            format.total_words,
+            format.order.as_str()
+        ],
+    )?;
+
+    let id = conn.last_insert_rowid() as i32;
+    fetch_game_format(&conn, id)
+}
+
+/// Update a custom game format
+///
+/// Games record the format their words were drawn with, so the ladder of a
+/// format used by a saved game, the game in progress or a word set cannot
+/// change (it can still be renamed).
+#[tauri::command]
+pub fn update_game_format(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    id: i32,
+    name: String,
+    format: GameFormat,
+) -> Result<GameFormatPreset, AppError> {
+    let name = validate_format_name(&name)?;
+    validate_format(&format)?;
+
+    let conn = db.conn()?;
+    let preset = fetch_game_format(&conn, id)?;
+    ensure_custom(&preset)?;
+
+    if preset.format != format {
+        if let Some(usage) = format_usage(&conn, &db, &game, id)? {
+            return Err(AppError::ValidationError(format!(
+                "{}, format değiştirilemez. Yeni bir oyun formatı oluşturun",
+                usage
+            )));
+        }
+    }
+
+    conn.execute(
+        "UPDATE game_formats
+         SET name = ?1, min_length = ?2, max_length = ?3, words_per_length = ?4,
+             total_words = ?5, word_order = ?6
+         WHERE id = ?7",
+        params![
+            name,
+            format.min_length,
+            format.max_length,
+            format.words_per_length,
+            format.total_words,
+            format.order.as_str(),
+            id
+        ],
+    )?;
+
+    fetch_game_format(&conn, id)
+}
+
+/// Delete a custom game format
+///
+/// Formats used by a saved game, the game in progress or a word set are kept.
+#[tauri::command]
+pub fn delete_game_format(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    id: i32,
+) -> Result<(), AppError> {
+    let conn = db.conn()?;
+    ensure_custom(&fetch_game_format(&conn, id)?)?;
+
+    if let Some(usage) = format_usage(&conn, &db, &game, id)? {
+        return Err(AppError::ValidationError(format!(
+            "{}, format silinemez",
+            usage
+        )));
+    }
+
+    conn.execute("DELETE FROM game_formats WHERE id = ?1", [id])?;
+
+    Ok(())
+}
+
+/// Load a game format by ID using an existing connection
+pub(crate) fn fetch_game_format(conn: &Connection, id: i32) -> Result<GameFormatPreset, AppError> {
+    conn.query_row(
+        &format!("SELECT {} FROM game_formats WHERE id = ?1", FORMAT_COLUMNS),
+        [id],
+        format_from_row,
+    )
+    .map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => {
+            AppError::NotFoundError("Oyun formatı bulunamadı".to_string())
+        }
+        e => e.into(),
+    })
+}
+
+/// The format used when none is named (the first built-in format)
+pub(crate) fn default_game_format(conn: &Connection) -> Result<GameFormatPreset, AppError> {
+    Ok(conn.query_row(
+        &format!(
+            "SELECT {} FROM game_formats ORDER BY is_default DESC, id ASC LIMIT 1",
+            FORMAT_COLUMNS
+        ),
+        [],
+        format_from_row,
+    )?)
+}
+
+/// Resolve an optional format ID to a format
+pub(crate) fn resolve_game_format(
+    conn: &Connection,
+    format_id: Option<i32>,
+) -> Result<GameFormat, AppError> {
+    let preset = match format_id {
+        Some(id) => fetch_game_format(conn, id)?,
+        None => default_game_format(conn)?,
+    };
+
+    Ok(preset.format)
+}
+
+fn format_from_row(row: &Row<'_>) -> rusqlite::Result<GameFormatPreset> {
+    let order: String = row.get(6)?;
+
+    Ok(GameFormatPreset {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        format: GameFormat {
+            min_length: row.get(2)?,
+            max_length: row.get(3)?,
+            words_per_length: row.get(4)?,
+            total_words: row.get(5)?,
+            order: WordOrder::from_db(&order),
+        },
+        is_default: row.get(7)?,
+        created_at: row.get(8)?,
+    })
+}
+
+/// Why the format must stay as it is, `None` when nothing uses it
+fn format_usage(
+    conn: &Connection,
+    db: &DbState,
+    game: &GameSessionState,
+    id: i32,
+) -> Result<Option<&'static str>, AppError> {
+    let db_path = db.path()?;
+    let in_game = game.current()?.is_some_and(|session| {
+        session.database_path == db_path
+            && session
+                .word_draw
+                .is_some_and(|draw| draw.game_format_id == Some(id))
+    });
+    if in_game {
+        return Ok(Some("Devam eden oyun bu oyun formatıyla oynanıyor"));
+    }
+
+    let in_history: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM game_history WHERE game_format_id = ?1)",
+        [id],
+        |row| row.get(0),
+    )?;
+    if in_history {
+        return Ok(Some("Bu oyun formatıyla oynanmış oyunlar var"));
+    }
+
+    let in_word_set: bool = conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM word_sets WHERE game_format_id = ?1)",
+        [id],
+        |row| row.get(0),
+    )?;
+    if in_word_set {
+        return Ok(Some("Bu oyun formatını kullanan kelime setleri var"));
+    }
+
+    Ok(None)
+}
+
+fn validate_format_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::ValidationError(
+            "Oyun formatı adı boş olamaz".to_string(),
+        ));
+    }
+
+    Ok(name.to_string())
+}
+
+fn ensure_custom(format: &GameFormatPreset) -> Result<(), AppError> {
+    if format.is_default {
+        return Err(AppError::ValidationError(
+            "Hazır oyun formatları değiştirilemez veya silinemez".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,417 @@
+//! Game session commands
+//!
+//! The frontend drives the game through these commands; scoring and timers
+//! are applied by the engine in `crate::game`. Finished games are saved to
+//! history from the engine's state.
+
+use super::category::fetch_category;
+use super::format::{default_game_format, resolve_game_format};
+use super::game_history::{
+    fetch_game_history, insert_game_session, GameSessionData, ParticipantData, WordResultData,
+};
+use super::scoring::{default_scoring_preset, fetch_scoring_preset};
+use super::word::{allocate_words_with, WordPreferences};
+use super::word_set::deal_word_set;
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::game::word_set::game_category;
+use crate::game::{
+    scoring, selection, GameMode, GamePhase, GameSession, GameSessionState, SessionConfig,
+};
+use crate::models::{SelectionPreferences, WordAllocation, WordDraw};
+use rand::Rng;
+use rusqlite::{Connection, OptionalExtension};
+use serde::Deserialize;
+use tauri::State;
+
+/// Guess timer used when the frontend does not send one
+const DEFAULT_GUESS_TIME_SECONDS: u32 = 30;
+
+/// Game duration used when neither the frontend nor the settings provide one
+const DEFAULT_GAME_TIME_SECONDS: u32 = 300;
+
+/// Options for starting a game
+#[derive(Debug, Deserialize)]
+pub struct GameSetup {
+    /// Category to draw from; with a word set it must be a category of the
+    /// set's words and defaults to the one most of them come from
+    pub category_id: Option<i32>,
+    pub mode: GameMode,
+    /// Player or team names in playing order
+    pub participants: Vec<String>,
+    /// Time per participant, defaults to the `default_time` setting
+    pub game_duration_seconds: Option<u32>,
+    /// Guess timer, defaults to 30 seconds
+    pub guess_duration_seconds: Option<u32>,
+    /// Scoring preset, defaults to the standard rules
+    pub scoring_preset_id: Option<i32>,
+    /// Word-length ladder, defaults to the standard format
+    pub game_format_id: Option<i32>,
+    /// Recent words to avoid and difficulty to aim for
+    #[serde(flatten)]
+    pub preferences: SelectionPreferences,
+    /// Seed of the word draw, a new one if omitted
+    pub seed: Option<u64>,
+    /// Draw the words of this saved game again, ignoring the format,
+    /// preferences and seed above
+    pub replay_game_id: Option<i32>,
+    /// Play the words of this word set instead of a draw, with the set's format
+    pub word_set_id: Option<i32>,
+}
+
+/// Start a new game, replacing any game in progress
+///
+/// Words are selected here (disjoint sets of comparable difficulty per
+/// participant) or taken from a word set, so the frontend only needs the
+/// returned session.
+#[tauri::command]
+pub fn start_game_session(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    setup: GameSetup,
+) -> Result<GameSession, AppError> {
+    let session = {
+        let conn = db.conn()?;
+        let (category_id, words, relaxed, word_draw, word_set) = match setup.word_set_id {
+            Some(_) if setup.replay_game_id.is_some() => {
+                return Err(AppError::ValidationError(
+                    "Kelime seti ve oyun tekrarı birlikte kullanılamaz".to_string(),
+                ));
+            }
+            Some(set_id) => {
+                let (set, words) = deal_word_set(&conn, set_id, setup.participants.len())?;
+                let category_ids: Vec<i32> = words
+                    .iter()
+                    .flatten()
+                    .map(|word| word.category_id)
+                    .collect();
+                let category_id = game_category(&category_ids, setup.category_id)?;
+                (category_id, words, Vec::new(), None, Some(set))
+            }
+            None => {
+                let category_id = setup
+                    .category_id
+                    .ok_or_else(|| AppError::ValidationError("Kategori seçilmedi".to_string()))?;
+                let (draw, allocation) = draw_words(&conn, category_id, &setup)?;
+                (
+                    category_id,
+                    allocation.words,
+                    allocation.relaxed,
+                    Some(draw),
+                    None,
+                )
+            }
+        };
+        let category = fetch_category(&conn, category_id)?;
+
+        let preset = match setup.scoring_preset_id {
+            Some(id) => fetch_scoring_preset(&conn, id)?,
+            None => default_scoring_preset(&conn)?,
+        };
+
+        let total_time_seconds = match setup.game_duration_seconds {
+            Some(seconds) => seconds,
+            None => default_game_time(&conn)?,
+        };
+
+        let mut session = GameSession::new(
+            SessionConfig {
+                id: format!("{:016x}", rand::thread_rng().gen::<u64>()),
+                category_id: category.id,
+                category_name: category.name,
+                category_emoji: category.emoji,
+                language: category.language,
+                mode: setup.mode,
+                participant_names: setup.participants,
+                total_time_seconds,
+                guess_time_seconds: setup
+                    .guess_duration_seconds
+                    .unwrap_or(DEFAULT_GUESS_TIME_SECONDS),
+                scoring: preset.rules,
+                scoring_preset_id: Some(preset.id),
+                started_at: now_iso(&conn)?,
+            },
+            words,
+        )?;
+        session.relaxed_constraints = relaxed;
+        session.word_draw = word_draw;
+        if let Some(set) = word_set {
+            session.word_set_id = Some(set.id);
+            session.word_set_name = Some(set.name);
+        }
+        session.database_path = db.path()?;
+        session
+    };
+
+    game.start(session)
+}
+
+/// Draw the words of every participant from `category_id` for `setup`, or
+/// repeat a saved draw
+fn draw_words(
+    conn: &Connection,
+    category_id: i32,
+    setup: &GameSetup,
+) -> Result<(WordDraw, WordAllocation), AppError> {
+    let (draw, before_game) = match setup.replay_game_id {
+        Some(game_id) => (replayed_draw(conn, game_id, category_id)?, Some(game_id)),
+        None => {
+            // The default format is recorded by ID too, a replay must not
+            // depend on which format is the default later
+            let game_format_id = match setup.game_format_id {
+                Some(id) => id,
+                None => default_game_format(conn)?.id,
+            };
+            let draw = WordDraw {
+                seed: setup.seed.unwrap_or_else(selection::new_seed),
+                game_format_id: Some(game_format_id),
+                preferences: setup.preferences,
+            };
+            (draw, None)
+        }
+    };
+    let format = resolve_game_format(conn, draw.game_format_id)?;
+    let preferences = WordPreferences::load(conn, category_id, &draw.preferences, before_game)?;
+
+    let allocation = allocate_words_with(
+        conn,
+        category_id,
+        &format,
+        setup.participants.len(),
+        &[],
+        &preferences,
+        draw.seed,
+    )?;
+
+    Ok((draw, allocation))
+}
+
+/// Word draw of a saved game, which must be of `category_id`
+///
+/// Replaying it draws the same words as long as the words of the category
+/// did not change; only the history before that game is taken into account.
+fn replayed_draw(conn: &Connection, game_id: i32, category_id: i32) -> Result<WordDraw, AppError> {
+    let history = fetch_game_history(conn, game_id)?;
+    if history.category_id != category_id {
+        return Err(AppError::ValidationError(
+            "Tekrarlanan oyun başka bir kategoriden".to_string(),
+        ));
+    }
+
+    if history.word_draw.is_none() && history.word_set_id.is_some() {
+        return Err(AppError::ValidationError(
+            "Bu oyun bir kelime setiyle oynandı, aynı kelimeler için kelime setini kullanın"
+                .to_string(),
+        ));
+    }
+
+    let draw = history.word_draw.ok_or_else(|| {
+        AppError::ValidationError("Bu oyunun kelime seçimi kaydedilmemiş".to_string())
+    })?;
+
+    // Another format would draw other words
+    if draw.game_format_id.is_none() {
+        return Err(AppError::ValidationError(
+            "Bu oyunun oyun formatı kaydedilmemiş, kelimeler aynı şekilde seçilemez".to_string(),
+        ));
+    }
+
+    Ok(draw)
+}
+
+/// Get the active game (timers up to date), if any
+#[tauri::command]
+pub fn get_game_session(
+    game: State<'_, GameSessionState>,
+) -> Result<Option<GameSession>, AppError> {
+    game.current()
+}
+
+/// Reveal a letter of the current word
+///
+/// Without `letter_index` the next letter in the language's reveal order is
+/// revealed.
+#[tauri::command]
+pub fn reveal_letter(
+    game: State<'_, GameSessionState>,
+    letter_index: Option<usize>,
+) -> Result<GameSession, AppError> {
+    game.update(|session| match letter_index {
+        Some(index) => session.reveal_letter(index),
+        None => session.reveal_next_letter(),
+    })
+}
+
+/// Enter guess mode
+#[tauri::command]
+pub fn start_guess(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::start_guess)
+}
+
+/// Resolve the current guess as correct or wrong
+#[tauri::command]
+pub fn submit_guess(
+    game: State<'_, GameSessionState>,
+    is_correct: bool,
+) -> Result<GameSession, AppError> {
+    game.update(|session| session.submit_guess(is_correct))
+}
+
+/// Resolve the current guess as timed out (also applied automatically by the timer)
+#[tauri::command]
+pub fn guess_timeout(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::guess_timeout)
+}
+
+/// Skip the current word without points
+#[tauri::command]
+pub fn skip_word(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::skip_word)
+}
+
+/// Continue with the next word after a result
+#[tauri::command]
+pub fn next_word(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::next_word)
+}
+
+/// Start the next participant's turn
+#[tauri::command]
+pub fn next_participant(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::next_participant)
+}
+
+/// Pause all timers
+#[tauri::command]
+pub fn pause_game(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::pause)
+}
+
+/// Resume the timers
+#[tauri::command]
+pub fn resume_game(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::resume)
+}
+
+/// End the game early
+#[tauri::command]
+pub fn end_game(game: State<'_, GameSessionState>) -> Result<GameSession, AppError> {
+    game.update(GameSession::end)
+}
+
+/// Save the finished game to history and clear it
+///
+/// The game is only saved to the database it was started on. Returns the
+/// ID of the new game history entry.
+#[tauri::command]
+pub fn finish_game_session(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+) -> Result<i32, AppError> {
+    let session = game
+        .current()?
+        .ok_or_else(|| AppError::NotFoundError("Aktif oyun yok".to_string()))?;
+    if session.phase != GamePhase::Finished {
+        return Err(AppError::ValidationError("Oyun henüz bitmedi".to_string()));
+    }
+    if session.database_path != db.path()? {
+        return Err(AppError::ValidationError(
+            "Oyun başka bir veritabanında başlatıldı, kaydedilemez".to_string(),
+        ));
+    }
+
+    let game_history_id = {
+        let conn = db.conn()?;
+        let played_at = now_iso(&conn)?;
+        insert_game_session(&conn, &to_history(&session, played_at))?
+    };
+
+    game.take()?;
+
+    Ok(game_history_id)
+}
+
+/// Discard the active game without saving it
+#[tauri::command]
+pub fn abandon_game_session(game: State<'_, GameSessionState>) -> Result<(), AppError> {
+    game.take()?;
+    Ok(())
+}
+
+/// Build the history record of a finished session
+fn to_history(session: &GameSession, played_at: String) -> GameSessionData {
+    let ranks = scoring::rank_participants(&session.participants);
+    let total_time: u32 = session
+        .participants
+        .iter()
+        .map(|participant| participant.elapsed_time_seconds)
+        .sum();
+
+    let participants = session
+        .participants
+        .iter()
+        .zip(ranks)
+        .map(|(participant, rank)| ParticipantData {
+            name: participant.name.clone(),
+            participant_type: participant.participant_type.as_str().to_string(),
+            score: participant.score,
+            words_found: participant.words_found,
+            words_skipped: participant.words_skipped,
+            letters_revealed: participant.letters_revealed,
+            elapsed_time_seconds: i32::try_from(participant.elapsed_time_seconds).ok(),
+            rank: Some(rank),
+            word_results: participant
+                .words
+                .iter()
+                .filter_map(|word| {
+                    word.result.map(|result| WordResultData {
+                        word_id: Some(word.id),
+                        word: word.word.clone(),
+                        word_hint: Some(word.hint.clone()),
+                        result: result.as_str().to_string(),
+                        points_earned: word.points_earned,
+                        letters_used: word.letters_revealed,
+                    })
+                })
+                .collect(),
+        })
+        .collect();
+
+    GameSessionData {
+        category_id: session.category_id,
+        category_name: session.category_name.clone(),
+        game_mode: session.mode.as_str().to_string(),
+        played_at,
+        total_time_seconds: i32::try_from(total_time).ok(),
+        scoring_preset_id: session.scoring_preset_id,
+        language: Some(session.language),
+        word_draw: session.word_draw.clone(),
+        word_set_id: session.word_set_id,
+        word_set_name: session.word_set_name.clone(),
+        participants,
+    }
+}
+
+/// Game duration from the `default_time` setting
+fn default_game_time(conn: &Connection) -> Result<u32, AppError> {
+    let value: Option<String> = conn
+        .query_row(
+            "SELECT value FROM settings WHERE key = 'default_time'",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    Ok(value
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_GAME_TIME_SECONDS))
+}
+
+/// Current time as an ISO 8601 UTC timestamp (same format the frontend used)
+fn now_iso(conn: &Connection) -> Result<String, AppError> {
+    Ok(
+        conn.query_row("SELECT strftime('%Y-%m-%dT%H:%M:%fZ', 'now')", [], |row| {
+            row.get(0)
+        })?,
+    )
+}
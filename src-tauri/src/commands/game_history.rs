@@ -1,38 +1,54 @@
 //! Game history management commands
 //! PRD Reference: Section 4.8 - Game History Screen
 
-use crate::db;
+use crate::db::DbState;
 use crate::errors::AppError;
-use crate::models::{GameHistory, GameParticipant, GameWordResult};
-use rusqlite::params;
+use crate::models::{
+    GameHistory, GameParticipant, GameWordResult, HistoryFilter, Language, WordDraw,
+};
+use crate::text;
+use rusqlite::{params, Connection, Row};
 use serde::{Deserialize, Serialize};
+use tauri::State;
+
+const HISTORY_COLUMNS: &str = "gh.id, gh.category_id, c.name as category_name, gh.game_mode,
+     gh.played_at, gh.total_time_seconds, gh.scoring_preset_id, gh.language, gh.created_at,
+     gh.seed, gh.game_format_id, gh.word_selection, gh.word_set_id, gh.word_set_name";
 
 /// Get all game history entries with optional filters
 #[tauri::command]
 pub fn get_all_game_history(
-    category_id: Option<i32>,
-    game_mode: Option<String>,
-    start_date: Option<String>,
-    end_date: Option<String>,
-    sort_by: Option<String>, // "date_desc", "date_asc", "score_desc"
-    limit: Option<i32>,
-    offset: Option<i32>,
+    db: State<'_, DbState>,
+    filter: Option<HistoryFilter>,
 ) -> Result<Vec<GameHistory>, AppError> {
-    let conn = db::get_connection()?;
+    let HistoryFilter {
+        category_id,
+        language,
+        game_mode,
+        start_date,
+        end_date,
+        sort_by,
+        limit,
+        offset,
+    } = filter.unwrap_or_default();
+    let conn = db.conn()?;
 
     // Build dynamic query
-    let mut query = String::from(
-        "SELECT gh.id, gh.category_id, c.name as category_name, gh.game_mode, 
-                gh.played_at, gh.total_time_seconds, gh.created_at
+    let mut query = format!(
+        "SELECT {}
          FROM game_history gh
          JOIN categories c ON gh.category_id = c.id
          WHERE 1=1",
+        HISTORY_COLUMNS
     );
 
     // Add filters
     if category_id.is_some() {
         query.push_str(" AND gh.category_id = ?");
     }
+    if language.is_some() {
+        query.push_str(" AND gh.language = ?");
+    }
     if game_mode.is_some() {
         query.push_str(" AND gh.game_mode = ?");
     }
@@ -64,6 +80,9 @@ pub fn get_all_game_history(
     if let Some(cat_id) = category_id {
         param_values.push(Box::new(cat_id));
     }
+    if let Some(language) = language {
+        param_values.push(Box::new(language.as_str()));
+    }
     if let Some(mode) = game_mode {
         param_values.push(Box::new(mode));
     }
@@ -77,17 +96,7 @@ pub fn get_all_game_history(
     let param_refs: Vec<&dyn rusqlite::ToSql> = param_values.iter().map(|b| b.as_ref()).collect();
 
     let histories = stmt
-        .query_map(param_refs.as_slice(), |row| {
-            Ok(GameHistory {
-                id: row.get(0)?,
-                category_id: row.get(1)?,
-                category_name: row.get(2)?,
-                game_mode: row.get(3)?,
-                played_at: row.get(4)?,
-                total_time_seconds: row.get(5)?,
-                created_at: row.get(6)?,
-            })
-        })?
+        .query_map(param_refs.as_slice(), history_from_row)?
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(histories)
@@ -95,38 +104,76 @@ pub fn get_all_game_history(
 
 /// Get a single game history entry by ID
 #[tauri::command]
-pub fn get_game_history_by_id(id: i32) -> Result<GameHistory, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_game_history_by_id(db: State<'_, DbState>, id: i32) -> Result<GameHistory, AppError> {
+    let conn = db.conn()?;
+    fetch_game_history(&conn, id)
+}
 
+/// Load a game history entry by ID using an existing connection
+pub(crate) fn fetch_game_history(conn: &Connection, id: i32) -> Result<GameHistory, AppError> {
     let history = conn.query_row(
-        "SELECT gh.id, gh.category_id, c.name as category_name, gh.game_mode, 
-                gh.played_at, gh.total_time_seconds, gh.created_at
-         FROM game_history gh
-         JOIN categories c ON gh.category_id = c.id
-         WHERE gh.id = ?1",
+        &format!(
+            "SELECT {}
+             FROM game_history gh
+             JOIN categories c ON gh.category_id = c.id
+             WHERE gh.id = ?1",
+            HISTORY_COLUMNS
+        ),
         [id],
-        |row| {
-            Ok(GameHistory {
-                id: row.get(0)?,
-                category_id: row.get(1)?,
-                category_name: row.get(2)?,
-                game_mode: row.get(3)?,
-                played_at: row.get(4)?,
-                total_time_seconds: row.get(5)?,
-                created_at: row.get(6)?,
-            })
-        },
+        history_from_row,
     )?;
 
     Ok(history)
 }
 
+fn history_from_row(row: &Row<'_>) -> rusqlite::Result<GameHistory> {
+    let language: String = row.get(7)?;
+    let seed: Option<i64> = row.get(9)?;
+    let word_selection: Option<String> = row.get(11)?;
+    // A draw whose preferences cannot be read is left out, replaying it with
+    // other preferences would draw other words
+    let preferences = word_selection.and_then(|json| serde_json::from_str(&json).ok());
+    let word_draw = match (seed.and_then(|seed| u64::try_from(seed).ok()), preferences) {
+        (Some(seed), Some(preferences)) => Some(WordDraw {
+            seed,
+            game_format_id: row.get(10)?,
+            preferences,
+        }),
+        _ => None,
+    };
+
+    Ok(GameHistory {
+        id: row.get(0)?,
+        category_id: row.get(1)?,
+        category_name: row.get(2)?,
+        game_mode: row.get(3)?,
+        played_at: row.get(4)?,
+        total_time_seconds: row.get(5)?,
+        scoring_preset_id: row.get(6)?,
+        language: Language::from_db(&language),
+        created_at: row.get(8)?,
+        word_draw,
+        word_set_id: row.get(12)?,
+        word_set_name: row.get(13)?,
+    })
+}
+
 /// Get participants for a game
 #[tauri::command]
-pub fn get_game_participants(game_history_id: i32) -> Result<Vec<GameParticipant>, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_game_participants(
+    db: State<'_, DbState>,
+    game_history_id: i32,
+) -> Result<Vec<GameParticipant>, AppError> {
+    let conn = db.conn()?;
+    fetch_participants(&conn, game_history_id)
+}
 
-    let mut stmt = conn.prepare(
+/// Participants of a game in rank order, using an existing connection
+pub(crate) fn fetch_participants(
+    conn: &Connection,
+    game_history_id: i32,
+) -> Result<Vec<GameParticipant>, AppError> {
+    let mut stmt = conn.prepare_cached(
         "SELECT id, game_history_id, participant_name, participant_type,
                 score, words_found, words_skipped, letters_revealed,
                 elapsed_time_seconds, rank, created_at
@@ -158,12 +205,22 @@ pub fn get_game_participants(game_history_id: i32) -> Result<Vec<GameParticipant
 
 /// Get word results for a participant
 #[tauri::command]
-pub fn get_participant_word_results(participant_id: i32) -> Result<Vec<GameWordResult>, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_participant_word_results(
+    db: State<'_, DbState>,
+    participant_id: i32,
+) -> Result<Vec<GameWordResult>, AppError> {
+    let conn = db.conn()?;
+    fetch_word_results(&conn, participant_id)
+}
 
-    let mut stmt = conn.prepare(
+/// Word results of a participant in play order, using an existing connection
+pub(crate) fn fetch_word_results(
+    conn: &Connection,
+    participant_id: i32,
+) -> Result<Vec<GameWordResult>, AppError> {
+    let mut stmt = conn.prepare_cached(
         "SELECT id, game_history_id, participant_id, word, word_hint, 
-                result, points_earned, letters_used, created_at
+                result, points_earned, letters_used, created_at, word_id
          FROM game_word_results
          WHERE participant_id = ?1
          ORDER BY id ASC",
@@ -181,6 +238,7 @@ pub fn get_participant_word_results(participant_id: i32) -> Result<Vec<GameWordR
                 points_earned: row.get(6)?,
                 letters_used: row.get(7)?,
                 created_at: row.get(8)?,
+                word_id: row.get(9)?,
             })
         })?
         .collect::<Result<Vec<_>, _>>()?;
@@ -189,13 +247,22 @@ pub fn get_participant_word_results(participant_id: i32) -> Result<Vec<GameWordR
 }
 
 /// Get game history statistics
+///
+/// With `language`, only games played in that language are counted.
 #[tauri::command]
-pub fn get_game_history_stats() -> Result<GameHistoryStats, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_game_history_stats(
+    db: State<'_, DbState>,
+    language: Option<Language>,
+) -> Result<GameHistoryStats, AppError> {
+    let conn = db.conn()?;
+    let language = language.map(Language::as_str);
 
     // Total games
-    let total_games: i32 =
-        conn.query_row("SELECT COUNT(*) FROM game_history", [], |row| row.get(0))?;
+    let total_games: i32 = conn.query_row(
+        "SELECT COUNT(*) FROM game_history WHERE ?1 IS NULL OR language = ?1",
+        [language],
+        |row| row.get(0),
+    )?;
 
     // Most played category
     let most_played_category = if total_games > 0 {
@@ -203,10 +270,11 @@ pub fn get_game_history_stats() -> Result<GameHistoryStats, AppError> {
             "SELECT c.name, c.emoji, COUNT(*) as play_count
              FROM game_history gh
              JOIN categories c ON gh.category_id = c.id
+             WHERE ?1 IS NULL OR gh.language = ?1
              GROUP BY gh.category_id
              ORDER BY play_count DESC
              LIMIT 1",
-            [],
+            [language],
             |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
         )
         .ok()
@@ -216,16 +284,20 @@ pub fn get_game_history_stats() -> Result<GameHistoryStats, AppError> {
 
     // Highest score
     let highest_score: i32 = conn.query_row(
-        "SELECT COALESCE(MAX(score), 0) FROM game_participants",
-        [],
+        "SELECT COALESCE(MAX(gp.score), 0)
+         FROM game_participants gp
+         JOIN game_history gh ON gp.game_history_id = gh.id
+         WHERE ?1 IS NULL OR gh.language = ?1",
+        [language],
         |row| row.get(0),
     )?;
 
     // Total play time (in seconds)
     let total_play_time: i32 = conn.query_row(
-        "SELECT COALESCE(SUM(total_time_seconds), 0) FROM game_history WHERE total_time_seconds IS NOT NULL",
-        [],
-        |row| row.get(0)
+        "SELECT COALESCE(SUM(total_time_seconds), 0) FROM game_history
+         WHERE total_time_seconds IS NOT NULL AND (?1 IS NULL OR language = ?1)",
+        [language],
+        |row| row.get(0),
     )?;
 
     Ok(GameHistoryStats {
@@ -238,11 +310,11 @@ pub fn get_game_history_stats() -> Result<GameHistoryStats, AppError> {
 
 /// Delete a game history entry (with cascade)
 #[tauri::command]
-pub fn delete_game_history(id: i32) -> Result<(), AppError> {
-    let conn = db::get_connection()?;
+pub fn delete_game_history(db: State<'_, DbState>, id: i32) -> Result<(), AppError> {
+    let conn = db.conn()?;
 
     // Check if exists
-    let _ = get_game_history_by_id(id)?;
+    let _ = fetch_game_history(&conn, id)?;
 
     // Delete (will cascade to participants and word_results)
     conn.execute("DELETE FROM game_history WHERE id = ?1", [id])?;
@@ -252,36 +324,66 @@ pub fn delete_game_history(id: i32) -> Result<(), AppError> {
 
 /// Delete all game history
 #[tauri::command]
-pub fn delete_all_game_history() -> Result<(), AppError> {
-    let conn = db::get_connection()?;
+pub fn delete_all_game_history(db: State<'_, DbState>) -> Result<(), AppError> {
+    let conn = db.conn()?;
 
     conn.execute("DELETE FROM game_history", [])?;
 
     Ok(())
 }
 
-/// Save a complete game session to history
-#[tauri::command]
-pub fn save_game_to_history(session: GameSessionData) -> Result<i32, AppError> {
-    let conn = db::get_connection()?;
-
+/// Insert a finished game with its participants and word results
+///
+/// Returns the ID of the new game history entry.
+pub(crate) fn insert_game_session(
+    conn: &Connection,
+    session: &GameSessionData,
+) -> Result<i32, AppError> {
     // Start transaction
     let tx = conn.unchecked_transaction()?;
 
+    let draw = session.word_draw.as_ref();
+    let seed = draw
+        .map(|draw| {
+            i64::try_from(draw.seed)
+                .map_err(|_| AppError::ValidationError("Geçersiz tohum".to_string()))
+        })
+        .transpose()?;
+    let word_selection = draw
+        .map(|draw| serde_json::to_string(&draw.preferences))
+        .transpose()?;
+
     // Insert game_history
     tx.execute(
-        "INSERT INTO game_history (category_id, category_name, game_mode, played_at, total_time_seconds)
-         VALUES (?1, ?2, ?3, ?4, ?5)",
+        "INSERT INTO game_history (category_id, category_name, game_mode, played_at, total_time_seconds, scoring_preset_id, language,
+                                   seed, game_format_id, word_selection, word_set_id, word_set_name)
+         VALUES (?1, ?2, ?3, ?4, ?5, (SELECT id FROM scoring_presets WHERE id = ?6),
+                 COALESCE(?7, (SELECT language FROM categories WHERE id = ?1), 'tr'),
+                 ?8, ?9, ?10,
+                 (SELECT id FROM word_sets WHERE id = ?11), ?12)",
         params![
             session.category_id,
             session.category_name,
             session.game_mode,
             session.played_at,
-            session.total_time_seconds
+            session.total_time_seconds,
+            session.scoring_preset_id,
+            session.language.map(Language::as_str),
+            seed,
+            draw.and_then(|draw| draw.game_format_id),
+            word_selection,
+            session.word_set_id,
+            session.word_set_name
         ],
     )?;
 
     let game_history_id = tx.last_insert_rowid() as i32;
+    let language: String = tx.query_row(
+        "SELECT language FROM game_history WHERE id = ?1",
+        [game_history_id],
+        |row| row.get(0),
+    )?;
+    let language = Language::from_db(&language);
 
     // Insert participants
     for participant in &session.participants {
@@ -304,12 +406,15 @@ pub fn save_game_to_history(session: GameSessionData) -> Result<i32, AppError> {
 
         let participant_id = tx.last_insert_rowid() as i32;
 
-        // Insert word results
+        // Insert word results, linking results by their text when the word ID is
+        // missing or the word was deleted during the game
         for word_result in &participant.word_results {
             tx.execute(
                 "INSERT INTO game_word_results 
-                 (game_history_id, participant_id, word, word_hint, result, points_earned, letters_used)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                 (game_history_id, participant_id, word, word_hint, result, points_earned, letters_used, word_id)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7,
+                         COALESCE((SELECT id FROM words WHERE id = ?8),
+                                  (SELECT id FROM words WHERE category_id = ?9 AND normalized_word = ?10)))",
                 params![
                     game_history_id,
                     participant_id,
@@ -317,7 +422,10 @@ pub fn save_game_to_history(session: GameSessionData) -> Result<i32, AppError> {
                     word_result.word_hint,
                     word_result.result,
                     word_result.points_earned,
-                    word_result.letters_used
+                    word_result.letters_used,
+                    word_result.word_id,
+                    session.category_id,
+                    text::collation_key(&word_result.word, language)
                 ],
             )?;
         }
@@ -338,18 +446,26 @@ pub struct GameHistoryStats {
     pub total_play_time_seconds: i32,
 }
 
-/// Data structure for saving a game session
-#[derive(Debug, Deserialize)]
+/// History record of a finished game, built from the game session state
+#[derive(Debug)]
 pub struct GameSessionData {
     pub category_id: i32,
     pub category_name: String,
     pub game_mode: String,
     pub played_at: String,
     pub total_time_seconds: Option<i32>,
+    pub scoring_preset_id: Option<i32>,
+    /// Defaults to the category's language
+    pub language: Option<Language>,
+    /// How the words were drawn, so the game can be replayed
+    pub word_draw: Option<WordDraw>,
+    /// Word set the game was played with
+    pub word_set_id: Option<i32>,
+    pub word_set_name: Option<String>,
     pub participants: Vec<ParticipantData>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct ParticipantData {
     pub name: String,
     pub participant_type: String,
@@ -362,8 +478,10 @@ pub struct ParticipantData {
     pub word_results: Vec<WordResultData>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct WordResultData {
+    /// Played word; looked up in the category by its text when missing
+    pub word_id: Option<i32>,
     pub word: String,
     pub word_hint: Option<String>,
     pub result: String,
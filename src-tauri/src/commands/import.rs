@@ -0,0 +1,471 @@
+//! Word list import commands
+//!
+//! Files are parsed by `crate::import`; this module validates the rows
+//! against the target category and writes them. An import runs in a single
+//! transaction: a preview runs the same import and rolls it back.
+
+use super::category::{category_validation, fetch_category, insert_category};
+use super::format::resolve_game_format;
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::export;
+use crate::import::{self, ParsedFile};
+use crate::models::{
+    Category, CategoryExportInfo, CategoryLanguage, ImportOptions, ImportPreview,
+    ImportPreviewWord, ImportResult, ImportRowError, ImportWordStatus, Language, MergeStrategy,
+};
+use crate::text::{self, WordProblem};
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+use tauri::State;
+
+/// Import a word list file into a category
+///
+/// Accepts CSV, TSV, Excel/OpenDocument workbooks, "WORD - hint" text files
+/// and category JSON exports. The format is detected from `file_name` and the
+/// contents unless set in `options`. Rows that cannot be added are listed in
+/// `ImportResult::errors` with their line number.
+#[tauri::command]
+pub fn import_words(
+    db: State<'_, DbState>,
+    category_id: i32,
+    file_name: String,
+    data: Vec<u8>,
+    options: Option<ImportOptions>,
+    strategy: Option<MergeStrategy>,
+) -> Result<ImportResult, AppError> {
+    let conn = db.conn()?;
+
+    let parsed = import::parse_file(&file_name, &data, &options.unwrap_or_default())?;
+
+    commit_import(
+        &conn,
+        &ImportTarget::Category(category_id),
+        parsed,
+        strategy.unwrap_or_default(),
+    )
+}
+
+/// Dry run of `import_words`
+///
+/// Reports what would happen to every word of the file and how the category
+/// would validate afterwards (under `format_id`, default format when omitted).
+#[tauri::command]
+pub fn preview_import_words(
+    db: State<'_, DbState>,
+    category_id: i32,
+    file_name: String,
+    data: Vec<u8>,
+    options: Option<ImportOptions>,
+    strategy: Option<MergeStrategy>,
+    format_id: Option<i32>,
+) -> Result<ImportPreview, AppError> {
+    let conn = db.conn()?;
+
+    let parsed = import::parse_file(&file_name, &data, &options.unwrap_or_default())?;
+
+    preview_import(
+        &conn,
+        &ImportTarget::Category(category_id),
+        parsed,
+        strategy.unwrap_or_default(),
+        format_id,
+    )
+}
+
+/// Dry run of `import_category_json`
+///
+/// When the category would be created it is created and rolled back as well,
+/// so the validation describes the new category.
+#[tauri::command]
+pub fn preview_import_category_json(
+    db: State<'_, DbState>,
+    category_id: Option<i32>,
+    json_data: serde_json::Value,
+    strategy: Option<MergeStrategy>,
+    format_id: Option<i32>,
+) -> Result<ImportPreview, AppError> {
+    let conn = db.conn()?;
+
+    let json_data = export::open(json_data)?.payload.into_category()?;
+
+    let target = match category_id {
+        Some(id) => ImportTarget::Category(id),
+        None => ImportTarget::New(&json_data.category),
+    };
+
+    preview_import(
+        &conn,
+        &target,
+        import::rows_from_export(&json_data),
+        strategy.unwrap_or_default(),
+        format_id,
+    )
+}
+
+/// Category an import writes into
+pub(crate) enum ImportTarget<'a> {
+    /// Existing category by ID
+    Category(i32),
+    /// Category described by an export, matched by name or created
+    New(&'a CategoryExportInfo),
+}
+
+/// Outcome of importing rows into one category (not committed yet)
+pub(crate) struct CategoryImport {
+    pub category: Category,
+    pub created: bool,
+    pub words: Vec<ImportPreviewWord>,
+}
+
+/// Import parsed rows into a category in one transaction
+pub(crate) fn commit_import(
+    conn: &Connection,
+    target: &ImportTarget<'_>,
+    parsed: ParsedFile,
+    strategy: MergeStrategy,
+) -> Result<ImportResult, AppError> {
+    let tx = conn.unchecked_transaction()?;
+    let outcome = import_into(&tx, target, parsed, strategy)?;
+    tx.commit()?;
+
+    Ok(import_result(outcome))
+}
+
+/// Run an import and roll it back, reporting every word and the resulting validation
+pub(crate) fn preview_import(
+    conn: &Connection,
+    target: &ImportTarget<'_>,
+    parsed: ParsedFile,
+    strategy: MergeStrategy,
+    format_id: Option<i32>,
+) -> Result<ImportPreview, AppError> {
+    let format = resolve_game_format(conn, format_id)?;
+
+    let tx = conn.unchecked_transaction()?;
+    let outcome = import_into(&tx, target, parsed, strategy)?;
+    let validation = category_validation(&tx, outcome.category.id, &format)?;
+    tx.rollback()?;
+
+    let with_status = |status| {
+        count(
+            outcome
+                .words
+                .iter()
+                .filter(|word| word.status == status)
+                .count(),
+        )
+    };
+    let words_accepted = with_status(ImportWordStatus::Accepted);
+    let words_rejected =
+        count(outcome.words.len()) - words_accepted - with_status(ImportWordStatus::HintUpdated);
+
+    Ok(ImportPreview {
+        category_id: if outcome.created {
+            0
+        } else {
+            outcome.category.id
+        },
+        category_name: outcome.category.name,
+        category_created: outcome.created,
+        words_accepted,
+        words_rejected,
+        words: outcome.words,
+        validation,
+    })
+}
+
+/// Resolve the target category and import the rows into it
+///
+/// The caller owns the transaction.
+pub(crate) fn import_into(
+    conn: &Connection,
+    target: &ImportTarget<'_>,
+    parsed: ParsedFile,
+    strategy: MergeStrategy,
+) -> Result<CategoryImport, AppError> {
+    let (category, created) = match target {
+        ImportTarget::Category(id) => (fetch_category(conn, *id)?, false),
+        ImportTarget::New(info) => match find_category_by_name(conn, &info.name)? {
+            Some(category) if strategy != MergeStrategy::Rename => (category, false),
+            _ => (create_import_category(conn, info, &parsed)?, true),
+        },
+    };
+
+    let words = import_rows(conn, &category, parsed, strategy)?;
+
+    Ok(CategoryImport {
+        category,
+        created,
+        words,
+    })
+}
+
+/// Summarize an import for the frontend
+pub(crate) fn import_result(outcome: CategoryImport) -> ImportResult {
+    let mut words_added = 0;
+    let mut hints_updated = 0;
+    let mut errors = Vec::new();
+    for word in outcome.words {
+        match word.status {
+            ImportWordStatus::Accepted => words_added += 1,
+            ImportWordStatus::HintUpdated => hints_updated += 1,
+            _ => errors.push(ImportRowError {
+                line: word.line,
+                word: word.word,
+                reason: word.reason.unwrap_or_default(),
+            }),
+        }
+    }
+    let words_skipped = count(errors.len());
+
+    let mut message = if words_added > 0 && words_skipped > 0 {
+        format!(
+            "{} kelime eklendi, {} kelime zaten vardı veya geçersizdi",
+            words_added, words_skipped
+        )
+    } else if words_added > 0 {
+        format!("{} kelime başarıyla eklendi", words_added)
+    } else {
+        format!(
+            "Hiç kelime eklenmedi, {} kelime zaten vardı veya geçersizdi",
+            words_skipped
+        )
+    };
+    if hints_updated > 0 {
+        message.push_str(&format!(", {} ipucu güncellendi", hints_updated));
+    }
+
+    ImportResult {
+        category_id: outcome.category.id,
+        category_name: outcome.category.name,
+        category_created: outcome.created,
+        words_added,
+        words_skipped,
+        hints_updated,
+        message,
+        errors,
+    }
+}
+
+/// Category with the given name (case-insensitive), if any
+fn find_category_by_name(conn: &Connection, name: &str) -> Result<Option<Category>, AppError> {
+    let key = text::collation_key(name, Language::Tr);
+    let id = category_names(conn)?
+        .into_iter()
+        .find(|(_, other)| text::collation_key(other, Language::Tr) == key)
+        .map(|(id, _)| id);
+
+    id.map(|id| fetch_category(conn, id)).transpose()
+}
+
+/// Create the category described by an export
+///
+/// A taken name gets a number ("Hayvanlar (2)"). Exports without a language
+/// or alphabet get the ones that fit their words.
+fn create_import_category(
+    conn: &Connection,
+    info: &CategoryExportInfo,
+    parsed: &ParsedFile,
+) -> Result<Category, AppError> {
+    let taken: HashSet<String> = category_names(conn)?
+        .into_iter()
+        .map(|(_, name)| text::collation_key(&name, Language::Tr))
+        .collect();
+    let base = info.name.trim();
+    // One of the first `taken.len() + 1` names is always free
+    let name = (1..=taken.len() + 1)
+        .map(|number| match number {
+            1 => base.to_string(),
+            _ => format!("{} ({})", base, number),
+        })
+        .find(|name| !taken.contains(&text::collation_key(name, Language::Tr)))
+        .unwrap_or_else(|| base.to_string());
+
+    let language = match (info.language, info.alphabet) {
+        (None, None) => {
+            let words: Vec<&str> = parsed.rows.iter().map(|row| row.word.as_str()).collect();
+            let (language, alphabet, custom_letters) = text::infer_alphabet(&words);
+            CategoryLanguage {
+                language: Some(language),
+                alphabet: Some(alphabet),
+                custom_letters,
+            }
+        }
+        (language, alphabet) => CategoryLanguage {
+            language,
+            alphabet,
+            custom_letters: info.custom_letters.clone(),
+        },
+    };
+
+    insert_category(
+        conn,
+        &name,
+        &info.emoji,
+        info.description.as_deref(),
+        language,
+    )
+}
+
+/// IDs and names of all categories
+fn category_names(conn: &Connection) -> Result<Vec<(i32, String)>, AppError> {
+    let mut stmt = conn.prepare_cached("SELECT id, name FROM categories ORDER BY id")?;
+    let names = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(names)
+}
+
+/// Where a collation key was first seen
+enum Existing {
+    /// Word already in the category
+    Word { id: i32, word: String, hint: String },
+    /// Earlier row of the same file
+    Line(usize),
+}
+
+/// Validate parsed rows against a category and insert the valid ones
+///
+/// Returns the outcome of every row, including the rows the parser already
+/// rejected, ordered by line. The caller owns the transaction.
+fn import_rows(
+    conn: &Connection,
+    category: &Category,
+    parsed: ParsedFile,
+    strategy: MergeStrategy,
+) -> Result<Vec<ImportPreviewWord>, AppError> {
+    let rules = text::WordRules::from(category);
+
+    // Collation keys of the words already in the category
+    let mut existing: HashMap<String, Existing> = conn
+        .prepare_cached("SELECT normalized_word, id, word, hint FROM words WHERE category_id = ?1")?
+        .query_map([category.id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                Existing::Word {
+                    id: row.get(1)?,
+                    word: row.get(2)?,
+                    hint: row.get(3)?,
+                },
+            ))
+        })?
+        .collect::<Result<_, _>>()?;
+
+    let mut words: Vec<ImportPreviewWord> = parsed.errors.into_iter().map(unreadable_row).collect();
+
+    let mut insert = conn.prepare_cached(
+        "INSERT INTO words (category_id, word, normalized_word, letter_count, hint)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+
+    for row in parsed.rows {
+        let mut outcome = ImportPreviewWord {
+            line: row.line,
+            word: row.word,
+            normalized: None,
+            letter_count: None,
+            hint: row.hint.trim().to_string(),
+            status: ImportWordStatus::Accepted,
+            reason: None,
+            duplicate_of: None,
+        };
+
+        // Normalize and validate against the category alphabet and length limits
+        let (word_upper, letter_count) = match text::check_word(&outcome.word, &rules) {
+            Ok(prepared) => prepared,
+            Err((problem, error)) => {
+                outcome.status = problem_status(problem);
+                outcome.reason = Some(error.message().to_string());
+                words.push(outcome);
+                continue;
+            }
+        };
+        outcome.normalized = Some(word_upper.clone());
+        outcome.letter_count = Some(letter_count);
+
+        // Validate letter_count matches actual length
+        if let Some(stated) = row.letter_count.filter(|&stated| stated != letter_count) {
+            outcome.status = ImportWordStatus::LetterCountMismatch;
+            outcome.reason = Some(format!(
+                "Harf sayısı uyuşmuyor (dosyada {}, kelimede {})",
+                stated, letter_count
+            ));
+            words.push(outcome);
+            continue;
+        }
+
+        // Check if word already exists in this category (or earlier in the file)
+        let normalized = text::collation_key(&word_upper, category.language);
+        match existing.get_mut(&normalized) {
+            Some(Existing::Word { id, word, hint }) => {
+                outcome.duplicate_of = Some(*id);
+                let replace_hint = strategy == MergeStrategy::OverwriteHints
+                    && !outcome.hint.is_empty()
+                    && outcome.hint != *hint;
+                if replace_hint {
+                    conn.execute(
+                        "UPDATE words SET hint = ?1 WHERE id = ?2",
+                        (&outcome.hint, *id),
+                    )?;
+                    hint.clone_from(&outcome.hint);
+                    outcome.status = ImportWordStatus::HintUpdated;
+                } else {
+                    outcome.status = ImportWordStatus::Duplicate;
+                    outcome.reason = Some(format!(
+                        "Bu kelime kategoride zaten var: {} (ID: {})",
+                        word, id
+                    ));
+                }
+            }
+            Some(Existing::Line(line)) => {
+                outcome.status = ImportWordStatus::Duplicate;
+                outcome.reason = Some(format!("Bu kelime dosyada {}. satırda da var", line));
+            }
+            None => {
+                // Insert the word
+                insert.execute((
+                    category.id,
+                    &word_upper,
+                    &normalized,
+                    letter_count,
+                    &outcome.hint,
+                ))?;
+                existing.insert(normalized, Existing::Line(outcome.line));
+            }
+        }
+        words.push(outcome);
+    }
+
+    words.sort_by_key(|word| word.line);
+    Ok(words)
+}
+
+/// Outcome of a row the parser could not read
+fn unreadable_row(error: ImportRowError) -> ImportPreviewWord {
+    ImportPreviewWord {
+        line: error.line,
+        word: error.word,
+        normalized: None,
+        letter_count: None,
+        hint: String::new(),
+        status: ImportWordStatus::MissingWord,
+        reason: Some(error.reason),
+        duplicate_of: None,
+    }
+}
+
+/// Import status of a word that failed a check
+const fn problem_status(problem: WordProblem) -> ImportWordStatus {
+    match problem {
+        WordProblem::Empty => ImportWordStatus::MissingWord,
+        WordProblem::InvalidLetters => ImportWordStatus::InvalidCharacters,
+        WordProblem::BadLength => ImportWordStatus::BadLength,
+    }
+}
+
+/// Count as the i32 used by the result models
+pub(crate) fn count(len: usize) -> i32 {
+    i32::try_from(len).unwrap_or(i32::MAX)
+}
@@ -0,0 +1,117 @@
+//! Word library bundle commands
+//!
+//! A bundle holds every category with its words in one export file
+//! (`crate::export`), so a whole library can be moved between installations
+//! or shared between teachers.
+
+use super::category::export_category;
+use super::import::{count, import_into, import_result, ImportTarget};
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::export;
+use crate::import;
+use crate::models::{
+    CategoryExportData, ExportFile, ExportPayload, LibraryImportResult, MergeStrategy,
+};
+use rusqlite::Connection;
+use tauri::State;
+
+/// Export every category and its words as one bundle
+#[tauri::command]
+pub fn export_library(
+    db: State<'_, DbState>,
+    author: Option<String>,
+) -> Result<ExportFile, AppError> {
+    let conn = db.conn()?;
+    library_export(&conn, author)
+}
+
+/// Import a library bundle
+///
+/// Each category of the bundle is matched to the category of the same name
+/// or created, as with `import_category_json` without a category ID. The
+/// whole bundle is imported in one transaction. A category export is
+/// accepted as a bundle of one category.
+#[tauri::command]
+pub fn import_library(
+    db: State<'_, DbState>,
+    bundle: serde_json::Value,
+    strategy: Option<MergeStrategy>,
+) -> Result<LibraryImportResult, AppError> {
+    let bundle = export::open(bundle)?.payload.into_categories();
+
+    let conn = db.conn()?;
+    import_categories(&conn, &bundle, strategy.unwrap_or_default())
+}
+
+/// Bundle of every category, using an existing connection
+pub(crate) fn library_export(
+    conn: &Connection,
+    author: Option<String>,
+) -> Result<ExportFile, AppError> {
+    let category_ids: Vec<i32> = conn
+        .prepare_cached("SELECT id FROM categories ORDER BY id")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    let categories = category_ids
+        .into_iter()
+        .map(|id| export_category(conn, id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let exported_at = conn.query_row("SELECT datetime('now')", [], |row| row.get(0))?;
+
+    export::seal(
+        ExportPayload::Library { categories },
+        Some(exported_at),
+        author,
+    )
+}
+
+/// Import exported categories in one transaction
+pub(crate) fn import_categories(
+    conn: &Connection,
+    bundle: &[CategoryExportData],
+    strategy: MergeStrategy,
+) -> Result<LibraryImportResult, AppError> {
+    let tx = conn.unchecked_transaction()?;
+
+    let mut categories = Vec::with_capacity(bundle.len());
+    for data in bundle {
+        let outcome = import_into(
+            &tx,
+            &ImportTarget::New(&data.category),
+            import::rows_from_export(data),
+            strategy,
+        )?;
+        categories.push(import_result(outcome));
+    }
+
+    tx.commit()?;
+
+    let categories_created = count(
+        categories
+            .iter()
+            .filter(|result| result.category_created)
+            .count(),
+    );
+    let categories_merged = count(categories.len()) - categories_created;
+    let words_added = categories.iter().map(|result| result.words_added).sum();
+    let words_skipped = categories.iter().map(|result| result.words_skipped).sum();
+    let hints_updated = categories.iter().map(|result| result.hints_updated).sum();
+
+    let message = format!(
+        "{} kategori oluşturuldu, {} kategori birleştirildi: {} kelime eklendi, {} kelime atlandı",
+        categories_created, categories_merged, words_added, words_skipped
+    );
+
+    Ok(LibraryImportResult {
+        categories_created,
+        categories_merged,
+        words_added,
+        words_skipped,
+        hints_updated,
+        categories,
+        message,
+    })
+}
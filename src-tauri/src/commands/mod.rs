@@ -4,13 +4,33 @@
 
 pub mod category;
 pub mod database;
+pub mod files;
+pub mod format;
+pub mod game;
 pub mod game_history;
+pub mod import;
+pub mod library;
+pub mod profile;
+pub mod report;
+pub mod scoring;
 pub mod settings;
+pub mod statistics;
 pub mod word;
+pub mod word_set;
 
 // Re-export all commands for easy registration
 pub use category::*;
 pub use database::*;
+pub use files::*;
+pub use format::*;
+pub use game::*;
 pub use game_history::*;
+pub use import::*;
+pub use library::*;
+pub use profile::*;
+pub use report::*;
+pub use scoring::*;
 pub use settings::*;
+pub use statistics::*;
 pub use word::*;
+pub use word_set::*;
@@ -0,0 +1,124 @@
+//! Profile ("classroom") management commands
+//!
+//! Each profile has its own database file. Switching profiles reopens the
+//! shared connection without restarting the application.
+
+use crate::db::profiles::{profile_db_path, Profile, ProfileRegistry, DEFAULT_PROFILE_ID};
+use crate::db::{self, DbState};
+use crate::errors::AppError;
+use crate::game::GameSessionState;
+use crate::models::{DatabaseLocation, ProfileInfo};
+use std::fs;
+use tauri::State;
+
+/// Get all profiles
+#[tauri::command]
+pub fn get_profiles() -> Result<Vec<ProfileInfo>, AppError> {
+    let registry = ProfileRegistry::load()?;
+
+    registry
+        .profiles
+        .iter()
+        .map(|profile| to_profile_info(&registry, profile))
+        .collect()
+}
+
+/// Get the location of the database currently in use
+#[tauri::command]
+pub fn get_database_location(db: State<'_, DbState>) -> Result<DatabaseLocation, AppError> {
+    let path = db.path()?;
+    let is_override = db::db_path_override().is_some();
+
+    let profile_id = if is_override {
+        None
+    } else {
+        Some(ProfileRegistry::load()?.active)
+    };
+
+    Ok(DatabaseLocation {
+        path: path.to_string_lossy().to_string(),
+        is_override,
+        profile_id,
+    })
+}
+
+/// Create a new profile with an empty (seeded) database
+#[tauri::command]
+pub fn create_profile(name: String) -> Result<ProfileInfo, AppError> {
+    let mut registry = ProfileRegistry::load()?;
+    let profile = registry.create(&name)?;
+
+    // Create the database file right away so errors surface here, not on switch
+    db::init_connection(&profile_db_path(&profile)?)?;
+
+    registry.save()?;
+
+    to_profile_info(&registry, &profile)
+}
+
+/// Switch to another profile
+///
+/// The new database is opened, migrated and seeded before the shared
+/// connection is replaced; a game in progress is discarded. Not available
+/// when the database path is overridden from the command line or environment.
+#[tauri::command]
+pub fn switch_profile(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    id: String,
+) -> Result<ProfileInfo, AppError> {
+    if db::db_path_override().is_some() {
+        return Err(AppError::ValidationError(
+            "Veritabanı yolu dışarıdan belirlendiği için profil değiştirilemez".to_string(),
+        ));
+    }
+
+    let mut registry = ProfileRegistry::load()?;
+    let profile = registry.get(&id)?.clone();
+
+    db.reopen(profile_db_path(&profile)?)?;
+    game.take()?;
+
+    registry.active = profile.id.clone();
+    registry.save()?;
+
+    to_profile_info(&registry, &profile)
+}
+
+/// Rename a profile (its database file is kept)
+#[tauri::command]
+pub fn rename_profile(id: String, name: String) -> Result<ProfileInfo, AppError> {
+    let mut registry = ProfileRegistry::load()?;
+    let profile = registry.rename(&id, &name)?;
+    registry.save()?;
+
+    to_profile_info(&registry, &profile)
+}
+
+/// Delete a profile and its database file
+///
+/// The default profile and the active profile cannot be deleted.
+#[tauri::command]
+pub fn delete_profile(id: String) -> Result<(), AppError> {
+    let mut registry = ProfileRegistry::load()?;
+    let profile = registry.remove(&id)?;
+    registry.save()?;
+
+    for file in db::database_files(&profile_db_path(&profile)?) {
+        if file.exists() {
+            fs::remove_file(&file)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn to_profile_info(registry: &ProfileRegistry, profile: &Profile) -> Result<ProfileInfo, AppError> {
+    Ok(ProfileInfo {
+        id: profile.id.clone(),
+        name: profile.name.clone(),
+        db_path: profile_db_path(profile)?.to_string_lossy().to_string(),
+        is_default: profile.id == DEFAULT_PROFILE_ID,
+        is_active: profile.id == registry.active,
+    })
+}
@@ -0,0 +1,96 @@
+//! Printable report commands
+//!
+//! Reports are built by `crate::report` and written to the given path as a
+//! standalone HTML page or a PDF.
+
+use super::category::{export_category, fetch_category};
+use super::game_history::{fetch_game_history, fetch_participants, fetch_word_results};
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::export;
+use crate::models::ReportFormat;
+use crate::report::{self, Report};
+use rusqlite::Connection;
+use std::io::Write;
+use std::path::Path;
+use tauri::State;
+
+/// Write a category's word list, hints and distribution by length
+///
+/// Without `format` the format follows the file extension (".pdf" or HTML).
+#[tauri::command]
+pub fn export_category_report(
+    db: State<'_, DbState>,
+    category_id: i32,
+    path: String,
+    format: Option<ReportFormat>,
+) -> Result<String, AppError> {
+    let conn = db.conn()?;
+
+    let category = fetch_category(&conn, category_id)?;
+    let words = export_category(&conn, category_id)?;
+    let report = report::category_report(&category, &words, generated_at(&conn)?);
+
+    write_report(&report, Path::new(&path), format)
+}
+
+/// Write the results of a game: ranking and every participant's words
+///
+/// Without `format` the format follows the file extension (".pdf" or HTML).
+#[tauri::command]
+pub fn export_game_report(
+    db: State<'_, DbState>,
+    game_history_id: i32,
+    path: String,
+    format: Option<ReportFormat>,
+) -> Result<String, AppError> {
+    let conn = db.conn()?;
+
+    let history = fetch_game_history(&conn, game_history_id)?;
+    let participants = fetch_participants(&conn, game_history_id)?
+        .into_iter()
+        .map(|participant| {
+            let results = fetch_word_results(&conn, participant.id)?;
+            Ok((participant, results))
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+    let report = report::game_report(&history, &participants, generated_at(&conn)?);
+
+    write_report(&report, Path::new(&path), format)
+}
+
+/// Local time shown in the report footer
+fn generated_at(conn: &Connection) -> Result<String, AppError> {
+    let now = conn.query_row(
+        "SELECT strftime('%Y-%m-%d %H:%M', 'now', 'localtime')",
+        [],
+        |row| row.get(0),
+    )?;
+
+    Ok(now)
+}
+
+fn write_report(
+    report: &Report,
+    path: &Path,
+    format: Option<ReportFormat>,
+) -> Result<String, AppError> {
+    let format = format.unwrap_or_else(|| {
+        let is_pdf = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("pdf"));
+        if is_pdf {
+            ReportFormat::Pdf
+        } else {
+            ReportFormat::Html
+        }
+    });
+
+    let contents = match format {
+        ReportFormat::Html => report::html::render(report).into_bytes(),
+        ReportFormat::Pdf => report::pdf::render(report),
+    };
+    export::write_atomic(path, |writer| Ok(writer.write_all(&contents)?))?;
+
+    Ok(format!("Rapor kaydedildi: {}", path.display()))
+}
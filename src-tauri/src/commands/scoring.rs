@@ -0,0 +1,204 @@
+//! Scoring preset commands
+//!
+//! Presets are named `ScoringRules` stored in the `scoring_presets` table.
+//! Built-in presets ("Standart", "Eksi Puan Yok", "Yarışma") cannot be
+//! changed or deleted; games reference the preset they were played with.
+
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::game::scoring::validate_rules;
+use crate::game::GameSessionState;
+use crate::models::{ScoringPreset, ScoringRules};
+use rusqlite::{params, Connection, Row};
+use tauri::State;
+
+const PRESET_COLUMNS: &str = "id, name, points_per_letter, wrong_penalty_factor,
+     timeout_penalty_factor, no_reveal_bonus, time_bonus_per_second, is_default, created_at";
+
+/// Get all scoring presets (built-in presets first)
+#[tauri::command]
+pub fn get_scoring_presets(db: State<'_, DbState>) -> Result<Vec<ScoringPreset>, AppError> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn.prepare_cached(&format!(
+        "SELECT {} FROM scoring_presets ORDER BY is_default DESC, id ASC",
+        PRESET_COLUMNS
+    ))?;
+
+    let presets = stmt
+        .query_map([], preset_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(presets)
+}
+
+/// Create a custom scoring preset
+#[tauri::command]
+pub fn create_scoring_preset(
+    db: State<'_, DbState>,
+    name: String,
+    rules: ScoringRules,
+) -> Result<ScoringPreset, AppError> {
+    let name = validate_preset_name(&name)?;
+    validate_rules(&rules)?;
+
+    let conn = db.conn()?;
+    conn.execute(
+        "INSERT INTO scoring_presets
+         (name, points_per_letter, wrong_penalty_factor, timeout_penalty_factor,
+          no_reveal_bonus, time_bonus_per_second, is_default)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0)",
+        params![
+            name,
+            rules.points_per_letter,
+            rules.wrong_penalty_factor,
+            rules.timeout_penalty_factor,
+            rules.no_reveal_bonus,
+            rules.time_bonus_per_second
+        ],
+    )?;
+
+    let id = conn.last_insert_rowid() as i32;
+    fetch_scoring_preset(&conn, id)
+}
+
+/// Update a custom scoring preset
+///
+/// Games reference the preset they were scored with, so the rules of a
+/// preset used by a saved game or the game in progress cannot change (it
+/// can still be renamed).
+#[tauri::command]
+pub fn update_scoring_preset(
+    db: State<'_, DbState>,
+    game: State<'_, GameSessionState>,
+    id: i32,
+    name: String,
+    rules: ScoringRules,
+) -> Result<ScoringPreset, AppError> {
+    let name = validate_preset_name(&name)?;
+    validate_rules(&rules)?;
+
+    let conn = db.conn()?;
+    let preset = fetch_scoring_preset(&conn, id)?;
+    ensure_custom(&preset)?;
+
+    if preset.rules != rules {
+        let db_path = db.path()?;
+        let in_game = game.current()?.is_some_and(|session| {
+            session.scoring_preset_id == Some(id) && session.database_path == db_path
+        });
+        if in_game || is_used_by_history(&conn, id)? {
+            return Err(AppError::ValidationError(
+                "Bu puanlama kuralıyla oynanmış oyunlar var, kurallar değiştirilemez. Yeni bir puanlama kuralı oluşturun"
+                    .to_string(),
+            ));
+        }
+    }
+
+    conn.execute(
+        "UPDATE scoring_presets
+         SET name = ?1, points_per_letter = ?2, wrong_penalty_factor = ?3,
+             timeout_penalty_factor = ?4, no_reveal_bonus = ?5, time_bonus_per_second = ?6
+         WHERE id = ?7",
+        params![
+            name,
+            rules.points_per_letter,
+            rules.wrong_penalty_factor,
+            rules.timeout_penalty_factor,
+            rules.no_reveal_bonus,
+            rules.time_bonus_per_second,
+            id
+        ],
+    )?;
+
+    fetch_scoring_preset(&conn, id)
+}
+
+/// Delete a custom scoring preset
+///
+/// Games played with it keep their scores, their preset reference is cleared.
+#[tauri::command]
+pub fn delete_scoring_preset(db: State<'_, DbState>, id: i32) -> Result<(), AppError> {
+    let conn = db.conn()?;
+    ensure_custom(&fetch_scoring_preset(&conn, id)?)?;
+
+    conn.execute("DELETE FROM scoring_presets WHERE id = ?1", [id])?;
+
+    Ok(())
+}
+
+/// Load a scoring preset by ID using an existing connection
+pub(crate) fn fetch_scoring_preset(conn: &Connection, id: i32) -> Result<ScoringPreset, AppError> {
+    conn.query_row(
+        &format!(
+            "SELECT {} FROM scoring_presets WHERE id = ?1",
+            PRESET_COLUMNS
+        ),
+        [id],
+        preset_from_row,
+    )
+    .map_err(|e| match e {
+        rusqlite::Error::QueryReturnedNoRows => {
+            AppError::NotFoundError("Puanlama kuralı bulunamadı".to_string())
+        }
+        e => e.into(),
+    })
+}
+
+/// The preset used when a game does not name one (the first built-in preset)
+pub(crate) fn default_scoring_preset(conn: &Connection) -> Result<ScoringPreset, AppError> {
+    Ok(conn.query_row(
+        &format!(
+            "SELECT {} FROM scoring_presets ORDER BY is_default DESC, id ASC LIMIT 1",
+            PRESET_COLUMNS
+        ),
+        [],
+        preset_from_row,
+    )?)
+}
+
+fn preset_from_row(row: &Row<'_>) -> rusqlite::Result<ScoringPreset> {
+    Ok(ScoringPreset {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        rules: ScoringRules {
+            points_per_letter: row.get(2)?,
+            wrong_penalty_factor: row.get(3)?,
+            timeout_penalty_factor: row.get(4)?,
+            no_reveal_bonus: row.get(5)?,
+            time_bonus_per_second: row.get(6)?,
+        },
+        is_default: row.get(7)?,
+        created_at: row.get(8)?,
+    })
+}
+
+/// Whether a saved game was scored with the preset
+fn is_used_by_history(conn: &Connection, id: i32) -> Result<bool, AppError> {
+    Ok(conn.query_row(
+        "SELECT EXISTS (SELECT 1 FROM game_history WHERE scoring_preset_id = ?1)",
+        [id],
+        |row| row.get(0),
+    )?)
+}
+
+fn validate_preset_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::ValidationError(
+            "Puanlama kuralı adı boş olamaz".to_string(),
+        ));
+    }
+
+    Ok(name.to_string())
+}
+
+fn ensure_custom(preset: &ScoringPreset) -> Result<(), AppError> {
+    if preset.is_default {
+        return Err(AppError::ValidationError(
+            "Hazır puanlama kuralları değiştirilemez veya silinemez".to_string(),
+        ));
+    }
+
+    Ok(())
+}
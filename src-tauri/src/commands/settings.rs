@@ -1,15 +1,16 @@
 //! Settings management commands
 
-use crate::db;
+use crate::db::DbState;
 use crate::errors::AppError;
 use crate::models::Settings;
+use tauri::State;
 
 /// Get all application settings
 #[tauri::command]
-pub fn get_settings() -> Result<Settings, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_settings(db: State<'_, DbState>) -> Result<Settings, AppError> {
+    let conn = db.conn()?;
 
-    let mut stmt = conn.prepare("SELECT key, value FROM settings")?;
+    let mut stmt = conn.prepare_cached("SELECT key, value FROM settings")?;
 
     let settings: Settings = stmt
         .query_map([], |row| {
@@ -26,8 +27,8 @@ pub fn get_settings() -> Result<Settings, AppError> {
 ///
 /// If the setting doesn't exist, it will be created
 #[tauri::command]
-pub fn update_setting(key: String, value: String) -> Result<(), AppError> {
-    let conn = db::get_connection()?;
+pub fn update_setting(db: State<'_, DbState>, key: String, value: String) -> Result<(), AppError> {
+    let conn = db.conn()?;
 
     // Use INSERT OR REPLACE to handle both insert and update
     conn.execute(
@@ -0,0 +1,132 @@
+//! Word statistics commands
+//!
+//! Aggregates `game_word_results` per word (linked through `word_id`) and
+//! scores the difficulty with `crate::game::difficulty`, so hints that are
+//! too hard or too easy can be spotted.
+
+use super::category::fetch_category;
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::game::difficulty::PlayCounts;
+use crate::models::WordStatistics;
+use rusqlite::{named_params, Connection, Row};
+use std::cmp::Ordering;
+use tauri::State;
+
+const STATISTICS_QUERY: &str = "SELECT w.id, w.category_id, w.word, w.hint, w.letter_count,
+            COUNT(r.id),
+            COUNT(CASE WHEN r.result = 'found' THEN 1 END),
+            COUNT(CASE WHEN r.result = 'wrong' THEN 1 END),
+            COUNT(CASE WHEN r.result = 'skipped' THEN 1 END),
+            COUNT(CASE WHEN r.result = 'timeout' THEN 1 END),
+            COUNT(CASE WHEN r.word_hint = w.hint THEN 1 END),
+            COALESCE(SUM(r.letters_used), 0),
+            COALESCE(SUM(r.points_earned), 0),
+            MAX(gh.played_at)
+     FROM words w
+     LEFT JOIN game_word_results r
+            ON r.word_id = w.id
+           AND r.result <> 'unplayed'
+           AND (:before_game IS NULL OR r.game_history_id < :before_game)
+     LEFT JOIN game_history gh ON gh.id = r.game_history_id";
+
+/// Get the statistics of every word in a category, hardest first
+///
+/// Words never played come last. `min_plays` leaves out words played fewer
+/// times than that.
+#[tauri::command]
+pub fn get_word_statistics(
+    db: State<'_, DbState>,
+    category_id: i32,
+    min_plays: Option<i32>,
+) -> Result<Vec<WordStatistics>, AppError> {
+    let conn = db.conn()?;
+    category_word_statistics(&conn, category_id, min_plays.unwrap_or(0), None)
+}
+
+/// Get the statistics of a single word
+#[tauri::command]
+pub fn get_word_statistics_by_id(
+    db: State<'_, DbState>,
+    word_id: i32,
+) -> Result<WordStatistics, AppError> {
+    let conn = db.conn()?;
+
+    let statistics = conn.query_row(
+        &format!("{} WHERE w.id = :word_id GROUP BY w.id", STATISTICS_QUERY),
+        named_params! { ":word_id": word_id, ":before_game": None::<i32> },
+        statistics_from_row,
+    )?;
+
+    Ok(statistics)
+}
+
+/// Statistics of the words of a category played at least `min_plays` times, hardest first
+///
+/// With `before_game` only games saved before that game count.
+pub(crate) fn category_word_statistics(
+    conn: &Connection,
+    category_id: i32,
+    min_plays: i32,
+    before_game: Option<i32>,
+) -> Result<Vec<WordStatistics>, AppError> {
+    // Fails with NotFoundError for unknown categories
+    fetch_category(conn, category_id)?;
+
+    let mut statistics = conn
+        .prepare(&format!(
+            "{} WHERE w.category_id = :category_id GROUP BY w.id HAVING COUNT(r.id) >= :min_plays
+             ORDER BY w.letter_count, w.word",
+            STATISTICS_QUERY
+        ))?
+        .query_map(
+            named_params! {
+                ":category_id": category_id,
+                ":min_plays": min_plays,
+                ":before_game": before_game,
+            },
+            statistics_from_row,
+        )?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    statistics.sort_by(|a, b| match (a.difficulty, b.difficulty) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+
+    Ok(statistics)
+}
+
+fn statistics_from_row(row: &Row<'_>) -> rusqlite::Result<WordStatistics> {
+    let letter_count: i32 = row.get(4)?;
+    let points: i32 = row.get(12)?;
+    let counts = PlayCounts {
+        played: row.get(5)?,
+        found: row.get(6)?,
+        timeout: row.get(9)?,
+        letters_revealed: row.get(11)?,
+    };
+
+    Ok(WordStatistics {
+        word_id: row.get(0)?,
+        category_id: row.get(1)?,
+        word: row.get(2)?,
+        hint: row.get(3)?,
+        letter_count,
+        times_played: counts.played,
+        times_found: counts.found,
+        times_wrong: row.get(7)?,
+        times_skipped: row.get(8)?,
+        times_timeout: counts.timeout,
+        plays_with_current_hint: row.get(10)?,
+        found_rate: counts.found_rate(),
+        timeout_rate: counts.timeout_rate(),
+        avg_letters_revealed: counts.avg_letters_revealed(),
+        avg_points: (counts.played > 0)
+            .then(|| (f64::from(points) / f64::from(counts.played) * 10.0).round() / 10.0),
+        difficulty: counts.difficulty(letter_count),
+        last_played_at: row.get(13)?,
+    })
+}
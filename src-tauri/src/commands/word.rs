@@ -1,15 +1,33 @@
 //! Word management commands
 
-use crate::db;
+use super::category::fetch_category;
+use super::format::resolve_game_format;
+use super::statistics::category_word_statistics;
+use crate::db::DbState;
 use crate::errors::AppError;
-use crate::models::Word;
+use crate::game::difficulty::{Band, UNPLAYED_SCORE};
+use crate::game::format::{length_quotas, max_participants};
+use crate::game::selection::{self, Candidate, Pick};
+use crate::models::{
+    DifficultyTarget, GameFormat, RecencyStrategy, RecentWordsFilter, RelaxedConstraint,
+    SelectionConstraint, SelectionPreferences, Word, WordAllocation, WordMerge, WordOrder,
+    WordSelection, WordSelectionOptions,
+};
+use crate::text;
+use rand::seq::SliceRandom;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use tauri::State;
 
 /// Get all words for a specific category
 #[tauri::command]
-pub fn get_words_by_category(category_id: i32) -> Result<Vec<Word>, AppError> {
-    let conn = db::get_connection()?;
+pub fn get_words_by_category(
+    db: State<'_, DbState>,
+    category_id: i32,
+) -> Result<Vec<Word>, AppError> {
+    let conn = db.conn()?;
 
-    let mut stmt = conn.prepare(
+    let mut stmt = conn.prepare_cached(
         "SELECT id, category_id, word, letter_count, hint, created_at
          FROM words
          WHERE category_id = ?1
@@ -34,73 +52,65 @@ pub fn get_words_by_category(category_id: i32) -> Result<Vec<Word>, AppError> {
 
 /// Add a new word to a category
 #[tauri::command]
-pub fn add_word(category_id: i32, word: String, hint: String) -> Result<Word, AppError> {
-    let conn = db::get_connection()?;
-
-    // Convert word to uppercase and calculate letter count
-    // Use chars().count() for proper Unicode character counting (Turkish: ş, ğ, ü, ö, ç, ı, İ)
-    let word_upper = word.to_uppercase();
-    let letter_count = word_upper.chars().count() as i32;
-
-    // Validate letter count (4-10)
-    if !(4..=10).contains(&letter_count) {
-        return Err(AppError::ValidationError(
-            "Kelime uzunluğu 4-10 harf arasında olmalıdır".to_string(),
-        ));
-    }
+pub fn add_word(
+    db: State<'_, DbState>,
+    category_id: i32,
+    word: String,
+    hint: String,
+) -> Result<Word, AppError> {
+    let conn = db.conn()?;
+
+    // Normalize, upper-case (category language rules) and check against its alphabet
+    let category = fetch_category(&conn, category_id)?;
+    let (word_upper, letter_count) = text::prepare_word(&word, &text::WordRules::from(&category))?;
+    let normalized = text::collation_key(&word_upper, category.language);
+    ensure_unique_word(&conn, category_id, &normalized, None)?;
 
     // Insert the word
     conn.execute(
-        "INSERT INTO words (category_id, word, letter_count, hint) VALUES (?1, ?2, ?3, ?4)",
-        (category_id, &word_upper, letter_count, &hint),
+        "INSERT INTO words (category_id, word, normalized_word, letter_count, hint)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        (category_id, &word_upper, &normalized, letter_count, &hint),
     )?;
 
     let id = conn.last_insert_rowid() as i32;
 
     // Fetch and return the created word
-    let word = conn.query_row(
-        "SELECT id, category_id, word, letter_count, hint, created_at
-         FROM words
-         WHERE id = ?1",
-        [id],
-        |row| {
-            Ok(Word {
-                id: row.get(0)?,
-                category_id: row.get(1)?,
-                word: row.get(2)?,
-                letter_count: row.get(3)?,
-                hint: row.get(4)?,
-                created_at: row.get(5)?,
-            })
-        },
-    )?;
-
-    Ok(word)
+    fetch_word(&conn, id)
 }
 
 /// Update an existing word
 #[tauri::command]
-pub fn update_word(id: i32, word: String, hint: String) -> Result<Word, AppError> {
-    let conn = db::get_connection()?;
-
-    // Convert word to uppercase and calculate letter count
-    // Use chars().count() for proper Unicode character counting (Turkish: ş, ğ, ü, ö, ç, ı, İ)
-    let word_upper = word.to_uppercase();
-    let letter_count = word_upper.chars().count() as i32;
-
-    // Validate letter count (4-10)
-    if !(4..=10).contains(&letter_count) {
-        return Err(AppError::ValidationError(
-            "Kelime uzunluğu 4-10 harf arasında olmalıdır".to_string(),
-        ));
-    }
+pub fn update_word(
+    db: State<'_, DbState>,
+    id: i32,
+    word: String,
+    hint: String,
+) -> Result<Word, AppError> {
+    let conn = db.conn()?;
+
+    // Normalize, upper-case (category language rules) and check against its alphabet
+    let category_id: i32 = conn
+        .query_row("SELECT category_id FROM words WHERE id = ?1", [id], |row| {
+            row.get(0)
+        })
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => {
+                AppError::NotFoundError("Kelime bulunamadı".to_string())
+            }
+            e => e.into(),
+        })?;
+    let category = fetch_category(&conn, category_id)?;
+    let (word_upper, letter_count) = text::prepare_word(&word, &text::WordRules::from(&category))?;
+    let normalized = text::collation_key(&word_upper, category.language);
+    ensure_unique_word(&conn, category_id, &normalized, Some(id))?;
 
     // Update the word
     let rows_affected = conn.execute(
         "UPDATE words
-         SET word = ?1, letter_count = ?2, hint = ?3
-         WHERE id = ?4",
-        (&word_upper, letter_count, &hint, id),
+         SET word = ?1, normalized_word = ?2, letter_count = ?3, hint = ?4
+         WHERE id = ?5",
+        (&word_upper, &normalized, letter_count, &hint, id),
     )?;
 
     if rows_affected == 0 {
@@ -108,6 +118,69 @@ pub fn update_word(id: i32, word: String, hint: String) -> Result<Word, AppError
     }
 
     // Fetch and return the updated word
+    fetch_word(&conn, id)
+}
+
+/// List the duplicate words merged when the unique-word constraint was added
+///
+/// Newest merges first. Empty for databases that never had duplicates.
+#[tauri::command]
+pub fn get_word_merges(db: State<'_, DbState>) -> Result<Vec<WordMerge>, AppError> {
+    let conn = db.conn()?;
+
+    let mut stmt = conn.prepare_cached(
+        "SELECT id, category_id, kept_word_id, removed_word_id, word, hint, merged_at
+         FROM word_merges
+         ORDER BY id DESC",
+    )?;
+
+    let merges = stmt
+        .query_map([], |row| {
+            Ok(WordMerge {
+                id: row.get(0)?,
+                category_id: row.get(1)?,
+                kept_word_id: row.get(2)?,
+                removed_word_id: row.get(3)?,
+                word: row.get(4)?,
+                hint: row.get(5)?,
+                merged_at: row.get(6)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(merges)
+}
+
+/// Fail with a DuplicateError naming the existing word if the category
+/// already has a word with this collation key
+///
+/// `exclude_id` skips the word being updated.
+pub(crate) fn ensure_unique_word(
+    conn: &Connection,
+    category_id: i32,
+    normalized_word: &str,
+    exclude_id: Option<i32>,
+) -> Result<(), AppError> {
+    let existing = conn
+        .query_row(
+            "SELECT id, word FROM words
+             WHERE category_id = ?1 AND normalized_word = ?2 AND (?3 IS NULL OR id != ?3)",
+            (category_id, normalized_word, exclude_id),
+            |row| Ok((row.get::<_, i32>(0)?, row.get::<_, String>(1)?)),
+        )
+        .optional()?;
+
+    match existing {
+        Some((existing_id, existing_word)) => Err(AppError::DuplicateError(format!(
+            "Bu kelime kategoride zaten var: {} (ID: {})",
+            existing_word, existing_id
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Load a word by ID using an existing connection
+pub(crate) fn fetch_word(conn: &Connection, id: i32) -> Result<Word, AppError> {
     let word = conn.query_row(
         "SELECT id, category_id, word, letter_count, hint, created_at
          FROM words
@@ -130,8 +203,8 @@ pub fn update_word(id: i32, word: String, hint: String) -> Result<Word, AppError
 
 /// Delete a word
 #[tauri::command]
-pub fn delete_word(id: i32) -> Result<(), AppError> {
-    let conn = db::get_connection()?;
+pub fn delete_word(db: State<'_, DbState>, id: i32) -> Result<(), AppError> {
+    let conn = db.conn()?;
 
     let rows_affected = conn.execute("DELETE FROM words WHERE id = ?1", [id])?;
 
@@ -144,44 +217,313 @@ pub fn delete_word(id: i32) -> Result<(), AppError> {
 
 /// Get random words for game play
 ///
-/// Selects exactly 2 words from each letter length (4-10) for a total of 14 words.
+/// Selects the words of one participant according to the game format
+/// (standard format: 2 words from each letter length 4-10, 14 words).
 /// Excludes words with IDs in the exclude_ids list (used in multiplayer mode).
 ///
 /// # Arguments
 /// * `category_id` - Category to select words from
 /// * `exclude_ids` - List of word IDs to exclude (already selected for other players)
+/// * `format_id` - Game format to use (default format if omitted)
 ///
 /// # Returns
-/// * Vector of exactly 14 random words (2 per letter length 4-10)
+/// * Vector of `total_words` random words, ordered as the format says
+#[tauri::command]
+pub fn get_random_words(
+    db: State<'_, DbState>,
+    category_id: i32,
+    exclude_ids: Vec<i32>,
+    format_id: Option<i32>,
+) -> Result<Vec<Word>, AppError> {
+    let conn = db.conn()?;
+    let format = resolve_game_format(&conn, format_id)?;
+    select_random_words(&conn, category_id, &format, &exclude_ids)
+}
+
+/// Select words for one participant, optionally avoiding recently played
+/// words and aiming at a difficulty
+///
+/// Relaxed constraints (e.g. recently played words used because a letter
+/// length had too few others) are listed in the result.
 #[tauri::command]
-pub fn get_random_words(category_id: i32, exclude_ids: Vec<i32>) -> Result<Vec<Word>, AppError> {
-    let conn = db::get_connection()?;
-    let mut selected_words = Vec::new();
-
-    // For each letter length (4-10), select 2 random words
-    for letter_count in 4..=10 {
-        // Build exclude clause
-        let exclude_clause = if exclude_ids.is_empty() {
-            String::new()
-        } else {
-            let ids = exclude_ids
-                .iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            format!("AND id NOT IN ({})", ids)
+pub fn select_words(
+    db: State<'_, DbState>,
+    category_id: i32,
+    options: Option<WordSelectionOptions>,
+) -> Result<WordSelection, AppError> {
+    let options = options.unwrap_or_default();
+    let conn = db.conn()?;
+    let format = resolve_game_format(&conn, options.format_id)?;
+    let preferences = WordPreferences::load(&conn, category_id, &options.preferences, None)?;
+
+    select_words_with(
+        &conn,
+        category_id,
+        &format,
+        &options.exclude_ids,
+        &preferences,
+        options.seed.unwrap_or_else(selection::new_seed),
+    )
+}
+
+/// Select disjoint words for every participant of a game in one call
+///
+/// The words of each letter length are dealt so that the participants get
+/// comparable difficulty. Takes the same options as `select_words`.
+#[tauri::command]
+pub fn allocate_words(
+    db: State<'_, DbState>,
+    category_id: i32,
+    participant_count: i32,
+    options: Option<WordSelectionOptions>,
+) -> Result<WordAllocation, AppError> {
+    let participants = usize::try_from(participant_count)
+        .ok()
+        .filter(|&count| count > 0)
+        .ok_or_else(|| AppError::ValidationError("Katılımcı sayısı en az 1 olmalı".to_string()))?;
+    let options = options.unwrap_or_default();
+
+    let conn = db.conn()?;
+    // One snapshot of words and history for all participants
+    let tx = conn.unchecked_transaction()?;
+    let format = resolve_game_format(&tx, options.format_id)?;
+    let preferences = WordPreferences::load(&tx, category_id, &options.preferences, None)?;
+    let allocation = allocate_words_with(
+        &tx,
+        category_id,
+        &format,
+        participants,
+        &options.exclude_ids,
+        &preferences,
+        options.seed.unwrap_or_else(selection::new_seed),
+    )?;
+    tx.commit()?;
+
+    Ok(allocation)
+}
+
+/// Select the words for one participant using an existing connection
+pub(crate) fn select_random_words(
+    conn: &Connection,
+    category_id: i32,
+    format: &GameFormat,
+    exclude_ids: &[i32],
+) -> Result<Vec<Word>, AppError> {
+    let selection = select_words_with(
+        conn,
+        category_id,
+        format,
+        exclude_ids,
+        &WordPreferences::default(),
+        selection::new_seed(),
+    )?;
+    Ok(selection.words)
+}
+
+/// What word selection aims for besides the lengths of the format
+#[derive(Default)]
+pub(crate) struct WordPreferences {
+    recent: Option<RecentPlays>,
+    target: Option<DifficultyTarget>,
+    /// Word ID -> difficulty score, for words that were played
+    scores: HashMap<i32, f64>,
+}
+
+impl WordPreferences {
+    /// Load the play history the preferences and balancing need
+    ///
+    /// With `before_game` only games saved before that game count, so the
+    /// draw of that game can be repeated.
+    pub(crate) fn load(
+        conn: &Connection,
+        category_id: i32,
+        preferences: &SelectionPreferences,
+        before_game: Option<i32>,
+    ) -> Result<Self, AppError> {
+        let recent = match &preferences.avoid_recent {
+            Some(filter) => Some(RecentPlays::load(conn, category_id, filter, before_game)?),
+            None => None,
         };
 
-        let query = format!(
-            "SELECT id, category_id, word, letter_count, hint, created_at
-             FROM words
-             WHERE category_id = ?1 AND letter_count = ?2 {}
-             ORDER BY RANDOM()
-             LIMIT 2",
-            exclude_clause
-        );
+        let scores = category_word_statistics(conn, category_id, 1, before_game)?
+            .into_iter()
+            .filter_map(|stats| Some((stats.word_id, stats.difficulty?)))
+            .collect();
+
+        Ok(Self {
+            recent,
+            target: preferences.difficulty,
+            scores,
+        })
+    }
+
+    fn candidate(&self, word: Word) -> Candidate {
+        let mut candidate = match &self.recent {
+            Some(recent) => recent.candidate(word),
+            None => Candidate::fresh(word),
+        };
+        candidate.difficulty = self.score(candidate.word.id);
+        candidate
+    }
+
+    fn score(&self, word_id: i32) -> f64 {
+        self.scores.get(&word_id).copied().unwrap_or(UNPLAYED_SCORE)
+    }
+
+    fn strategy(&self) -> RecencyStrategy {
+        self.recent
+            .as_ref()
+            .map_or(RecencyStrategy::Exclude, |recent| recent.strategy)
+    }
+
+    /// Band aimed at for the `slot`-th of `slots` letter lengths
+    fn band(&self, slot: usize, slots: usize) -> Option<Band> {
+        self.target.map(|target| match target {
+            DifficultyTarget::Easy => Band::Easy,
+            DifficultyTarget::Medium => Band::Medium,
+            DifficultyTarget::Hard => Band::Hard,
+            DifficultyTarget::Ramp => Band::ramp(slot, slots),
+        })
+    }
+}
+
+/// Recent plays of the words of a category
+struct RecentPlays {
+    strategy: RecencyStrategy,
+    /// Word ID -> (plays in the window, most recent game with 0 the latest)
+    plays: HashMap<i32, (u32, usize)>,
+}
+
+impl RecentPlays {
+    /// Collect the plays in the window of `filter`
+    ///
+    /// With `before_game` the window ends at that game instead of now.
+    fn load(
+        conn: &Connection,
+        category_id: i32,
+        filter: &RecentWordsFilter,
+        before_game: Option<i32>,
+    ) -> Result<Self, AppError> {
+        if filter.games.is_none() && filter.days.is_none() {
+            return Err(AppError::ValidationError(
+                "Son oyunlar için oyun veya gün sayısı verilmeli".to_string(),
+            ));
+        }
+        if filter.games.is_some_and(|games| games < 1) || filter.days.is_some_and(|days| days < 1) {
+            return Err(AppError::ValidationError(
+                "Oyun ve gün sayısı sıfırdan büyük olmalı".to_string(),
+            ));
+        }
+
+        let games = usize::try_from(filter.games.unwrap_or(0)).unwrap_or(0);
+        let since = filter.days.map(|days| format!("-{} days", days));
+
+        // Games that played words of the category (word sets can mix categories),
+        // latest first, with whether they fall in the day window
+        let window: HashMap<i32, usize> = conn
+            .prepare(
+                "SELECT gh.id, ?2 IS NOT NULL AND julianday(gh.played_at) >= julianday(
+                        COALESCE((SELECT played_at FROM game_history WHERE id = ?3), 'now'), ?2)
+                 FROM game_history gh
+                 WHERE (?3 IS NULL OR gh.id < ?3)
+                   AND (gh.category_id = ?1 OR EXISTS (
+                        SELECT 1 FROM game_word_results r
+                        JOIN words w ON w.id = r.word_id
+                        WHERE r.game_history_id = gh.id AND w.category_id = ?1))
+                 ORDER BY gh.played_at DESC, gh.id DESC",
+            )?
+            .query_map(params![category_id, since, before_game], |row| {
+                Ok((row.get::<_, i32>(0)?, row.get::<_, bool>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .enumerate()
+            .filter(|&(index, (_, in_days))| index < games || in_days)
+            .map(|(index, (id, _))| (id, index))
+            .collect();
+
+        let mut plays: HashMap<i32, (u32, usize)> = HashMap::new();
+        let mut stmt = conn.prepare(
+            "SELECT r.word_id, r.game_history_id
+             FROM game_word_results r
+             JOIN words w ON w.id = r.word_id
+             WHERE w.category_id = ?1",
+        )?;
+        let rows = stmt.query_map([category_id], |row| {
+            Ok((row.get::<_, i32>(0)?, row.get::<_, i32>(1)?))
+        })?;
+        for row in rows {
+            let (word_id, game_id) = row?;
+            if let Some(&index) = window.get(&game_id) {
+                let entry = plays.entry(word_id).or_insert((0, index));
+                entry.0 += 1;
+                entry.1 = entry.1.min(index);
+            }
+        }
 
-        let mut stmt = conn.prepare(&query)?;
+        Ok(Self {
+            strategy: filter.strategy,
+            plays,
+        })
+    }
+
+    fn candidate(&self, word: Word) -> Candidate {
+        match self.plays.get(&word.id) {
+            Some(&(recent_plays, last_played)) => Candidate {
+                recent_plays,
+                last_played: Some(last_played),
+                ..Candidate::fresh(word)
+            },
+            None => Candidate::fresh(word),
+        }
+    }
+}
+
+/// Select the words for one participant from the words of each length of the format
+pub(crate) fn select_words_with(
+    conn: &Connection,
+    category_id: i32,
+    format: &GameFormat,
+    exclude_ids: &[i32],
+    preferences: &WordPreferences,
+    seed: u64,
+) -> Result<WordSelection, AppError> {
+    let allocation =
+        allocate_words_with(conn, category_id, format, 1, exclude_ids, preferences, seed)?;
+
+    Ok(WordSelection {
+        words: allocation.words.into_iter().next().unwrap_or_default(),
+        relaxed: allocation.relaxed,
+        seed: allocation.seed,
+    })
+}
+
+/// Select disjoint words for `participants` participants with comparable difficulty
+///
+/// Every letter length must have enough words for all participants, otherwise
+/// the error names each length that is short. The same `seed` draws the same
+/// words as long as the words and the play history it looks at are unchanged.
+pub(crate) fn allocate_words_with(
+    conn: &Connection,
+    category_id: i32,
+    format: &GameFormat,
+    participants: usize,
+    exclude_ids: &[i32],
+    preferences: &WordPreferences,
+    seed: u64,
+) -> Result<WordAllocation, AppError> {
+    let mut rng = selection::seeded(seed)?;
+    let mut stmt = conn.prepare_cached(
+        "SELECT id, category_id, word, letter_count, hint, created_at
+         FROM words
+         WHERE category_id = ?1 AND letter_count = ?2
+         ORDER BY id",
+    )?;
+
+    let quotas = length_quotas(format);
+    let mut pools = Vec::with_capacity(quotas.len());
+    let mut shortages = Vec::new();
+    for &(letter_count, quota) in &quotas {
         let words: Vec<Word> = stmt
             .query_map([category_id, letter_count], |row| {
                 Ok(Word {
@@ -193,24 +535,112 @@ pub fn get_random_words(category_id: i32, exclude_ids: Vec<i32>) -> Result<Vec<W
                     created_at: row.get(5)?,
                 })
             })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        if words.len() < 2 {
-            return Err(AppError::ValidationError(format!(
-                "Kategori için yeterli {} harfli kelime yok (en az 2 gerekli, {} bulundu)",
+            .collect::<Result<_, _>>()?;
+
+        let candidates: Vec<Candidate> = words
+            .into_iter()
+            .filter(|word| !exclude_ids.contains(&word.id))
+            .map(|word| preferences.candidate(word))
+            .collect();
+
+        let needed = quota as usize * participants;
+        if candidates.len() < needed {
+            shortages.push(format!(
+                "{} harfli en az {} gerekli, {} bulundu",
                 letter_count,
-                words.len()
-            )));
+                needed,
+                candidates.len()
+            ));
         }
+        pools.push(candidates);
+    }
+
+    if !shortages.is_empty() {
+        return Err(AppError::ValidationError(format!(
+            "Kategoride {} katılımcı için yeterli kelime yok: {}",
+            participants,
+            shortages.join("; ")
+        )));
+    }
 
-        selected_words.extend(words);
+    let mut words: Vec<Vec<Word>> = vec![Vec::new(); participants];
+    let mut totals = vec![0.0; participants];
+    let mut relaxed = Vec::new();
+
+    // For each letter length of the format, select its quota of random words
+    // for everyone, then deal them out
+    for (slot, (candidates, &(letter_count, quota))) in pools.into_iter().zip(&quotas).enumerate() {
+        let pick = selection::pick(
+            candidates,
+            quota as usize * participants,
+            preferences.strategy(),
+            preferences.band(slot, quotas.len()),
+            &mut rng,
+        );
+        relaxed.extend(relaxed_constraints(letter_count, &pick));
+
+        let scored = pick
+            .words
+            .into_iter()
+            .map(|word| {
+                let score = preferences.score(word.id);
+                (word, score)
+            })
+            .collect();
+        let dealt = selection::distribute(scored, quota as usize, &mut totals);
+        for (participant, dealt) in words.iter_mut().zip(dealt) {
+            participant.extend(dealt);
+        }
     }
 
-    // DO NOT shuffle! Words must be presented in order by length (4,4,5,5,6,6,7,7,8,8,9,9,10,10)
-    // as per game rules: "Kelimeler artan zorlukta ilerler"
-    // Each pair (2 words of same length) is already randomized by RANDOM() in SQL query
+    // Ascending formats keep the length order (4,4,5,5,...,10,10) as per game rules:
+    // "Kelimeler artan zorlukta ilerler". Random formats are shuffled, or
+    // played easiest first when the difficulty ramps up.
+    if format.order == WordOrder::Random {
+        for participant in &mut words {
+            participant.shuffle(&mut rng);
+            if preferences.target == Some(DifficultyTarget::Ramp) {
+                participant
+                    .sort_by(|a, b| preferences.score(a.id).total_cmp(&preferences.score(b.id)));
+            }
+        }
+    }
 
-    Ok(selected_words)
+    Ok(WordAllocation {
+        words,
+        relaxed,
+        seed,
+    })
+}
+
+/// Constraints a pick of `letter_count` letter words had to drop
+fn relaxed_constraints(letter_count: i32, pick: &Pick) -> Vec<RelaxedConstraint> {
+    let mut relaxed = Vec::new();
+
+    if pick.band_fallback > 0 {
+        relaxed.push(RelaxedConstraint {
+            constraint: SelectionConstraint::Difficulty,
+            letter_count,
+            words_affected: i32::try_from(pick.band_fallback).unwrap_or(i32::MAX),
+            message: format!(
+                "{} harfli hedef zorlukta yeterli kelime yok, en yakın zorluktaki {} kelime kullanıldı",
+                letter_count, pick.band_fallback
+            ),
+        });
+    }
+    if pick.recent_fallback > 0 {
+        relaxed.push(RelaxedConstraint {
+            constraint: SelectionConstraint::AvoidRecent,
+            letter_count,
+            words_affected: i32::try_from(pick.recent_fallback).unwrap_or(i32::MAX),
+            message: format!(
+                "{} harfli yeterli yeni kelime yok, son oyunlarda çıkmış {} kelime kullanıldı",
+                letter_count, pick.recent_fallback
+            ),
+        });
+    }
+
+    relaxed
 }
 
 /// Validate if a category has enough words for a specific game mode
@@ -219,45 +649,47 @@ pub fn get_random_words(category_id: i32, exclude_ids: Vec<i32>) -> Result<Vec<W
 /// * `category_id` - Category to validate
 /// * `mode` - Game mode: "single", "multi", or "team"
 /// * `participant_count` - Number of players or teams
+/// * `format_id` - Game format to check against (default format if omitted)
 ///
 /// # Returns
 /// * true if category has enough words, false otherwise
 #[tauri::command]
 pub fn validate_category_for_mode(
+    db: State<'_, DbState>,
     category_id: i32,
     _mode: String,
     participant_count: i32,
+    format_id: Option<i32>,
 ) -> Result<bool, AppError> {
-    let conn = db::get_connection()?;
-
-    // Calculate required words
-    // Each participant needs 14 words (2 per letter length 4-10)
-    let required_words_total = participant_count * 14;
-    let required_words_per_length = participant_count * 2;
-
-    // Check total word count
-    let total_words: i32 = conn.query_row(
-        "SELECT COUNT(*) FROM words WHERE category_id = ?1",
-        [category_id],
-        |row| row.get(0),
-    )?;
-
-    if total_words < required_words_total {
-        return Ok(false);
-    }
-
-    // Check each letter length has enough words
-    for letter_count in 4..=10 {
-        let count: i32 = conn.query_row(
-            "SELECT COUNT(*) FROM words WHERE category_id = ?1 AND letter_count = ?2",
-            [category_id, letter_count],
-            |row| row.get(0),
-        )?;
+    let conn = db.conn()?;
+    let format = resolve_game_format(&conn, format_id)?;
+
+    // Each participant needs the format's quota of every letter length
+    let counts = count_words_by_length(&conn, category_id, &format)?;
+    let supported = max_participants(&format, |letter_count| {
+        counts
+            .iter()
+            .find(|&&(length, _)| length == letter_count)
+            .map_or(0, |&(_, count)| count)
+    });
+
+    Ok(supported >= participant_count)
+}
 
-        if count < required_words_per_length {
-            return Ok(false);
-        }
-    }
+/// Word counts of a category for every letter length of a format
+pub(crate) fn count_words_by_length(
+    conn: &Connection,
+    category_id: i32,
+    format: &GameFormat,
+) -> Result<Vec<(i32, i32)>, AppError> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT COUNT(*) FROM words WHERE category_id = ?1 AND letter_count = ?2",
+    )?;
 
-    Ok(true)
+    (format.min_length..=format.max_length)
+        .map(|letter_count| {
+            let count: i32 = stmt.query_row([category_id, letter_count], |row| row.get(0))?;
+            Ok((letter_count, count))
+        })
+        .collect()
 }
@@ -0,0 +1,322 @@
+//! Word set commands
+//!
+//! Word sets are named, ordered lists of words from one or more categories,
+//! stored in `word_sets` and `word_set_words`. A game can be started from a
+//! set instead of a random draw; the checks against the game format live in
+//! `crate::game::word_set`.
+
+use super::format::{fetch_game_format, resolve_game_format};
+use crate::db::DbState;
+use crate::errors::AppError;
+use crate::game::word_set::{participant_capacity, word_set_issues};
+use crate::models::{GameFormat, Language, Word, WordSet, WordSetValidation};
+use rusqlite::{params, Connection, Row};
+use std::collections::HashSet;
+use tauri::State;
+
+const WORD_SET_COLUMNS: &str = "id, name, description, game_format_id, created_at, updated_at";
+
+/// Get all word sets with their words, by name
+#[tauri::command]
+pub fn get_word_sets(db: State<'_, DbState>) -> Result<Vec<WordSet>, AppError> {
+    let conn = db.conn()?;
+
+    let mut sets = conn
+        .prepare_cached(&format!(
+            "SELECT {} FROM word_sets ORDER BY name COLLATE NOCASE ASC",
+            WORD_SET_COLUMNS
+        ))?
+        .query_map([], word_set_from_row)?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for set in &mut sets {
+        set.words = fetch_word_set_words(&conn, set.id)?;
+    }
+
+    Ok(sets)
+}
+
+/// Get a single word set by ID
+#[tauri::command]
+pub fn get_word_set_by_id(db: State<'_, DbState>, id: i32) -> Result<WordSet, AppError> {
+    let conn = db.conn()?;
+    fetch_word_set(&conn, id)
+}
+
+/// Create a word set from words of any categories, in playing order
+///
+/// The set is saved even if it does not fit its format yet; use
+/// `validate_word_set` to check it.
+#[tauri::command]
+pub fn create_word_set(
+    db: State<'_, DbState>,
+    name: String,
+    description: Option<String>,
+    game_format_id: Option<i32>,
+    word_ids: Vec<i32>,
+) -> Result<WordSet, AppError> {
+    let name = validate_word_set_name(&name)?;
+    let conn = db.conn()?;
+    if let Some(format_id) = game_format_id {
+        fetch_game_format(&conn, format_id)?;
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "INSERT INTO word_sets (name, description, game_format_id) VALUES (?1, ?2, ?3)",
+        params![name, description, game_format_id],
+    )?;
+    let id = tx.last_insert_rowid() as i32;
+    replace_word_set_words(&tx, id, &word_ids)?;
+    tx.commit()?;
+
+    fetch_word_set(&conn, id)
+}
+
+/// Update a word set, replacing its words
+#[tauri::command]
+pub fn update_word_set(
+    db: State<'_, DbState>,
+    id: i32,
+    name: String,
+    description: Option<String>,
+    game_format_id: Option<i32>,
+    word_ids: Vec<i32>,
+) -> Result<WordSet, AppError> {
+    let name = validate_word_set_name(&name)?;
+    let conn = db.conn()?;
+    fetch_word_set(&conn, id)?;
+    if let Some(format_id) = game_format_id {
+        fetch_game_format(&conn, format_id)?;
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "UPDATE word_sets
+         SET name = ?1, description = ?2, game_format_id = ?3, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ?4",
+        params![name, description, game_format_id, id],
+    )?;
+    replace_word_set_words(&tx, id, &word_ids)?;
+    tx.commit()?;
+
+    fetch_word_set(&conn, id)
+}
+
+/// Delete a word set
+///
+/// Games played with it keep the set's name in history.
+#[tauri::command]
+pub fn delete_word_set(db: State<'_, DbState>, id: i32) -> Result<(), AppError> {
+    let conn = db.conn()?;
+    fetch_word_set(&conn, id)?;
+
+    conn.execute("DELETE FROM word_sets WHERE id = ?1", [id])?;
+
+    Ok(())
+}
+
+/// Check whether a word set can be played with a game format
+///
+/// Uses the set's own format when `format_id` is omitted.
+#[tauri::command]
+pub fn validate_word_set(
+    db: State<'_, DbState>,
+    id: i32,
+    format_id: Option<i32>,
+) -> Result<WordSetValidation, AppError> {
+    let conn = db.conn()?;
+    let set = fetch_word_set(&conn, id)?;
+    let format = resolve_game_format(&conn, format_id.or(set.game_format_id))?;
+
+    word_set_validation(&conn, &set, &format)
+}
+
+/// Load a word set with its words using an existing connection
+pub(crate) fn fetch_word_set(conn: &Connection, id: i32) -> Result<WordSet, AppError> {
+    let mut set = conn
+        .query_row(
+            &format!("SELECT {} FROM word_sets WHERE id = ?1", WORD_SET_COLUMNS),
+            [id],
+            word_set_from_row,
+        )
+        .map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => {
+                AppError::NotFoundError("Kelime seti bulunamadı".to_string())
+            }
+            e => e.into(),
+        })?;
+    set.words = fetch_word_set_words(conn, id)?;
+
+    Ok(set)
+}
+
+/// Playability of a word set under a game format
+pub(crate) fn word_set_validation(
+    conn: &Connection,
+    set: &WordSet,
+    format: &GameFormat,
+) -> Result<WordSetValidation, AppError> {
+    let letter_counts: Vec<i32> = set.words.iter().map(|word| word.letter_count).collect();
+    let mut issues = word_set_issues(format, &letter_counts);
+    if word_set_languages(conn, set.id)?.len() > 1 {
+        issues.push("Kelime setinde farklı dillerde kelimeler var".to_string());
+    }
+
+    let is_valid = issues.is_empty();
+    let max_participants = if is_valid {
+        i32::try_from(participant_capacity(format, set.words.len())).unwrap_or(i32::MAX)
+    } else {
+        0
+    };
+    let message = if is_valid {
+        format!(
+            "✅ {} yarışmacıya/takıma kadar oynanabilir ({} kelime)",
+            max_participants,
+            set.words.len()
+        )
+    } else {
+        format!("❌ Oynanamaz: {}", issues.join("; "))
+    };
+
+    Ok(WordSetValidation {
+        is_valid,
+        word_count: i32::try_from(set.words.len()).unwrap_or(i32::MAX),
+        max_participants,
+        issues,
+        message,
+    })
+}
+
+/// Words of a set for each of `participants` participants of a game
+///
+/// The set must fit its format, be in a single language and have words for
+/// every participant; the first participants get the first words of the set.
+pub(crate) fn deal_word_set(
+    conn: &Connection,
+    id: i32,
+    participants: usize,
+) -> Result<(WordSet, Vec<Vec<Word>>), AppError> {
+    let set = fetch_word_set(conn, id)?;
+    let format = resolve_game_format(conn, set.game_format_id)?;
+
+    let validation = word_set_validation(conn, &set, &format)?;
+    if !validation.is_valid {
+        return Err(AppError::ValidationError(format!(
+            "Kelime seti oynanamaz: {}",
+            validation.issues.join("; ")
+        )));
+    }
+    let capacity = participant_capacity(&format, set.words.len());
+    if capacity < participants {
+        return Err(AppError::ValidationError(format!(
+            "Kelime seti en fazla {} katılımcı için yeterli",
+            capacity
+        )));
+    }
+
+    let per_participant = set.words.len() / capacity;
+    let words = set
+        .words
+        .chunks(per_participant)
+        .take(participants)
+        .map(<[Word]>::to_vec)
+        .collect();
+
+    Ok((set, words))
+}
+
+/// Languages of the categories the words of a set come from
+pub(crate) fn word_set_languages(conn: &Connection, id: i32) -> Result<Vec<Language>, AppError> {
+    let languages = conn
+        .prepare_cached(
+            "SELECT DISTINCT c.language
+             FROM word_set_words ws
+             JOIN words w ON w.id = ws.word_id
+             JOIN categories c ON c.id = w.category_id
+             WHERE ws.word_set_id = ?1
+             ORDER BY c.language",
+        )?
+        .query_map([id], |row| row.get::<_, String>(0))?
+        .map(|language| language.map(|language| Language::from_db(&language)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(languages)
+}
+
+fn fetch_word_set_words(conn: &Connection, id: i32) -> Result<Vec<Word>, AppError> {
+    let words = conn
+        .prepare_cached(
+            "SELECT w.id, w.category_id, w.word, w.letter_count, w.hint, w.created_at
+             FROM word_set_words ws
+             JOIN words w ON w.id = ws.word_id
+             WHERE ws.word_set_id = ?1
+             ORDER BY ws.position ASC",
+        )?
+        .query_map([id], |row| {
+            Ok(Word {
+                id: row.get(0)?,
+                category_id: row.get(1)?,
+                word: row.get(2)?,
+                letter_count: row.get(3)?,
+                hint: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(words)
+}
+
+/// Replace the words of a set, checking that every word exists once
+fn replace_word_set_words(conn: &Connection, id: i32, word_ids: &[i32]) -> Result<(), AppError> {
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = word_ids.iter().find(|&&word_id| !seen.insert(word_id)) {
+        return Err(AppError::ValidationError(format!(
+            "Kelime sette birden fazla kez var (ID: {})",
+            duplicate
+        )));
+    }
+
+    conn.execute("DELETE FROM word_set_words WHERE word_set_id = ?1", [id])?;
+
+    let mut exists = conn.prepare_cached("SELECT COUNT(*) FROM words WHERE id = ?1")?;
+    let mut insert = conn.prepare_cached(
+        "INSERT INTO word_set_words (word_set_id, word_id, position) VALUES (?1, ?2, ?3)",
+    )?;
+    for (position, &word_id) in word_ids.iter().enumerate() {
+        let count: i32 = exists.query_row([word_id], |row| row.get(0))?;
+        if count == 0 {
+            return Err(AppError::NotFoundError(format!(
+                "Kelime bulunamadı (ID: {})",
+                word_id
+            )));
+        }
+        insert.execute(params![id, word_id, position as i64])?;
+    }
+
+    Ok(())
+}
+
+fn word_set_from_row(row: &Row<'_>) -> rusqlite::Result<WordSet> {
+    Ok(WordSet {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        description: row.get(2)?,
+        game_format_id: row.get(3)?,
+        words: Vec::new(),
+        created_at: row.get(4)?,
+        updated_at: row.get(5)?,
+    })
+}
+
+fn validate_word_set_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::ValidationError(
+            "Kelime seti adı boş olamaz".to_string(),
+        ));
+    }
+
+    Ok(name.to_string())
+}
@@ -0,0 +1,363 @@
+//! Automatic rotating backups
+//!
+//! Backups are written to `<db dir>/backups/<db name>/<YYYY-MM-DD_HH-MM-SS>.db`
+//! and pruned with a daily/weekly retention policy. Configuration lives in the
+//! `settings` table:
+//! - `auto_backup_frequency`: "daily" | "on_exit" | "off"
+//! - `auto_backup_keep_daily`: newest backup of the last N days is kept
+//! - `auto_backup_keep_weekly`: newest backup of the last M weeks is kept
+//!
+//! The last failed scheduled or exit backup is recorded in the same table
+//! (`auto_backup_last_error`, `auto_backup_last_error_at`) and cleared by the
+//! next successful backup.
+
+use super::backup;
+use crate::errors::AppError;
+use crate::models::BackupEntry;
+use rusqlite::{Connection, OptionalExtension};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const FREQUENCY_KEY: &str = "auto_backup_frequency";
+pub const KEEP_DAILY_KEY: &str = "auto_backup_keep_daily";
+pub const KEEP_WEEKLY_KEY: &str = "auto_backup_keep_weekly";
+pub const LAST_ERROR_KEY: &str = "auto_backup_last_error";
+pub const LAST_ERROR_AT_KEY: &str = "auto_backup_last_error_at";
+
+const DEFAULT_KEEP_DAILY: usize = 7;
+const DEFAULT_KEEP_WEEKLY: usize = 4;
+
+/// When automatic backups are taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupFrequency {
+    Off,
+    Daily,
+    OnExit,
+}
+
+/// Automatic backup configuration read from the settings table
+#[derive(Debug, Clone, Copy)]
+pub struct AutoBackupConfig {
+    pub frequency: BackupFrequency,
+    pub keep_daily: usize,
+    pub keep_weekly: usize,
+}
+
+impl AutoBackupConfig {
+    /// Load the configuration, using defaults for missing or invalid values
+    pub fn load(conn: &Connection) -> Result<Self, AppError> {
+        let frequency = match read_setting(conn, FREQUENCY_KEY)?.as_deref() {
+            Some("off") => BackupFrequency::Off,
+            Some("on_exit") => BackupFrequency::OnExit,
+            _ => BackupFrequency::Daily,
+        };
+
+        let keep_daily = read_setting(conn, KEEP_DAILY_KEY)?
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_KEEP_DAILY);
+        let keep_weekly = read_setting(conn, KEEP_WEEKLY_KEY)?
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_KEEP_WEEKLY);
+
+        Ok(Self {
+            frequency,
+            keep_daily,
+            keep_weekly,
+        })
+    }
+}
+
+fn read_setting(conn: &Connection, key: &str) -> Result<Option<String>, AppError> {
+    Ok(conn
+        .query_row("SELECT value FROM settings WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()?)
+}
+
+/// Directory holding the automatic backups of a database
+pub fn backups_dir(db_path: &Path) -> PathBuf {
+    let stem = db_path
+        .file_stem()
+        .map_or_else(|| "word-game".into(), |stem| stem.to_string_lossy());
+
+    db_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("backups")
+        .join(stem.as_ref())
+}
+
+/// List automatic backups, newest first
+pub fn list_backups(db_path: &Path) -> Result<Vec<BackupEntry>, AppError> {
+    let dir = backups_dir(db_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        if let Some(created_at) = parse_backup_name(&file_name) {
+            backups.push(BackupEntry {
+                path: entry.path().to_string_lossy().to_string(),
+                size_bytes: entry.metadata()?.len(),
+                file_name,
+                created_at,
+            });
+        }
+    }
+
+    // File names sort chronologically
+    backups.sort_by(|a, b| b.file_name.cmp(&a.file_name));
+
+    Ok(backups)
+}
+
+/// Resolve a backup file name to its path, rejecting anything outside the backups directory
+pub fn backup_path(db_path: &Path, file_name: &str) -> Result<PathBuf, AppError> {
+    if parse_backup_name(file_name).is_none() {
+        return Err(AppError::ValidationError(format!(
+            "Geçersiz yedek dosyası adı: {}",
+            file_name
+        )));
+    }
+
+    let path = backups_dir(db_path).join(file_name);
+    if !path.exists() {
+        return Err(AppError::NotFoundError(format!(
+            "Yedek bulunamadı: {}",
+            file_name
+        )));
+    }
+
+    Ok(path)
+}
+
+/// Take a backup into the rotation and apply the retention policy
+pub fn create_backup(conn: &Connection, db_path: &Path) -> Result<BackupEntry, AppError> {
+    let timestamp: String = conn.query_row(
+        "SELECT strftime('%Y-%m-%d_%H-%M-%S', 'now', 'localtime')",
+        [],
+        |row| row.get(0),
+    )?;
+    let file_name = format!("{}.db", timestamp);
+    let path = backups_dir(db_path).join(&file_name);
+
+    let manifest = backup::backup_to(conn, &path)?;
+
+    let config = AutoBackupConfig::load(conn)?;
+    prune_backups(db_path, config.keep_daily, config.keep_weekly)?;
+
+    conn.execute(
+        "DELETE FROM settings WHERE key IN (?1, ?2)",
+        [LAST_ERROR_KEY, LAST_ERROR_AT_KEY],
+    )?;
+
+    Ok(BackupEntry {
+        created_at: parse_backup_name(&file_name).unwrap_or_default(),
+        path: path.to_string_lossy().to_string(),
+        size_bytes: manifest.size_bytes,
+        file_name,
+    })
+}
+
+/// Record a failed automatic backup so the app can show it
+pub fn record_failure(conn: &Connection, error: &AppError) -> Result<(), AppError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO settings (key, value) VALUES (?1, ?2)",
+        (LAST_ERROR_KEY, error.to_string()),
+    )?;
+    conn.execute(
+        "INSERT OR REPLACE INTO settings (key, value)
+         VALUES (?1, datetime('now', 'localtime'))",
+        [LAST_ERROR_AT_KEY],
+    )?;
+
+    Ok(())
+}
+
+/// Delete a backup together with its manifest
+pub fn delete_backup(path: &Path) -> Result<(), AppError> {
+    fs::remove_file(path)?;
+
+    let manifest = backup::manifest_path(path);
+    if manifest.exists() {
+        fs::remove_file(manifest)?;
+    }
+
+    Ok(())
+}
+
+/// Whether a daily backup should be taken now (no backup exists for today)
+pub fn is_daily_backup_due(conn: &Connection, db_path: &Path) -> Result<bool, AppError> {
+    let today: String = conn.query_row("SELECT date('now', 'localtime')", [], |row| row.get(0))?;
+
+    Ok(!list_backups(db_path)?
+        .iter()
+        .any(|backup| backup.created_at.starts_with(&today)))
+}
+
+/// Delete backups that fall outside the daily/weekly retention policy
+///
+/// Returns the number of deleted backups.
+pub fn prune_backups(
+    db_path: &Path,
+    keep_daily: usize,
+    keep_weekly: usize,
+) -> Result<usize, AppError> {
+    let backups = list_backups(db_path)?;
+    let days: Vec<i64> = backups
+        .iter()
+        .map(|backup| backup_day(&backup.file_name).unwrap_or(0))
+        .collect();
+
+    let keep = select_backups_to_keep(&days, keep_daily, keep_weekly);
+
+    let mut deleted = 0;
+    for (backup, keep) in backups.iter().zip(keep) {
+        if !keep {
+            delete_backup(Path::new(&backup.path))?;
+            deleted += 1;
+        }
+    }
+
+    Ok(deleted)
+}
+
+/// Decide which backups to keep
+///
+/// `days` are day numbers of backups sorted newest first. The newest backup of
+/// each of the `keep_daily` most recent days and of each of the `keep_weekly`
+/// most recent weeks (Monday based) is kept.
+fn select_backups_to_keep(days: &[i64], keep_daily: usize, keep_weekly: usize) -> Vec<bool> {
+    let mut seen_days = HashSet::new();
+    let mut seen_weeks = HashSet::new();
+
+    days.iter()
+        .map(|&day| {
+            let daily = seen_days.len() < keep_daily && seen_days.insert(day);
+            // Day 0 (1970-01-01) was a Thursday, shift so weeks start on Monday
+            let week = (day + 3).div_euclid(7);
+            let weekly = seen_weeks.len() < keep_weekly && seen_weeks.insert(week);
+            daily || weekly
+        })
+        .collect()
+}
+
+/// Parse `YYYY-MM-DD_HH-MM-SS.db` into `YYYY-MM-DD HH:MM:SS`
+fn parse_backup_name(file_name: &str) -> Option<String> {
+    let stamp = file_name.strip_suffix(".db")?;
+    let bytes = stamp.as_bytes();
+
+    let valid = bytes.len() == 19
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 | 13 | 16 => *b == b'-',
+            10 => *b == b'_',
+            _ => b.is_ascii_digit(),
+        });
+    if !valid {
+        return None;
+    }
+
+    Some(format!(
+        "{} {}",
+        &stamp[..10],
+        stamp[11..].replace('-', ":")
+    ))
+}
+
+/// Day number (days since 1970-01-01) of a backup file name
+fn backup_day(file_name: &str) -> Option<i64> {
+    let year = file_name.get(0..4)?.parse().ok()?;
+    let month = file_name.get(5..7)?.parse().ok()?;
+    let day = file_name.get(8..10)?.parse().ok()?;
+
+    Some(days_from_civil(year, month, day))
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Day numbers of backups taken on `dates` (newest first)
+    fn days(dates: &[&str]) -> Vec<i64> {
+        dates
+            .iter()
+            .map(|date| backup_day(&format!("{}_10-00-00.db", date)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn day_numbers_count_from_1970() {
+        assert_eq!(
+            days(&["1970-01-01", "1970-03-01", "2000-03-01"]),
+            [0, 59, 11_017]
+        );
+        assert_eq!(
+            parse_backup_name("2026-10-17_09-30-05.db").as_deref(),
+            Some("2026-10-17 09:30:05")
+        );
+        assert_eq!(parse_backup_name("word-game_pre_restore.db"), None);
+    }
+
+    #[test]
+    fn the_newest_backup_of_each_recent_day_is_kept() {
+        let days = days(&["2026-10-17", "2026-10-17", "2026-10-16", "2026-10-15"]);
+
+        assert_eq!(
+            select_backups_to_keep(&days, 2, 0),
+            [true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        // Saturday and Monday of one week, then Sunday and Monday of the week before
+        let days = days(&[
+            "2026-10-17",
+            "2026-10-12",
+            "2026-10-11",
+            "2026-10-05",
+            "2026-10-04",
+        ]);
+
+        assert_eq!(
+            select_backups_to_keep(&days, 0, 2),
+            [true, false, true, false, false]
+        );
+    }
+
+    #[test]
+    fn daily_and_weekly_backups_can_overlap() {
+        let days = days(&[
+            "2026-10-17",
+            "2026-10-16",
+            "2026-10-15",
+            "2026-10-11",
+            "2026-10-04",
+        ]);
+
+        // The newest backup counts for both its day and its week
+        assert_eq!(
+            select_backups_to_keep(&days, 2, 2),
+            [true, true, false, true, false]
+        );
+        assert_eq!(select_backups_to_keep(&days, 0, 0), [false; 5]);
+    }
+}
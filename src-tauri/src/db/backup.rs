@@ -0,0 +1,207 @@
+//! Online database backups
+//!
+//! Backups are taken with SQLite's online backup API, so a consistent
+//! snapshot is copied even while the application is using the database.
+//! Every backup is checked with `PRAGMA integrity_check` and gets a
+//! manifest (`<backup>.manifest.json`) describing its contents.
+
+use super::migrations::get_schema_version;
+use crate::errors::AppError;
+use crate::models::{BackupManifest, TableRowCount};
+use rusqlite::backup::Backup;
+use rusqlite::{Connection, OpenFlags};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Tables whose row counts are recorded in the manifest
+pub const BACKUP_TABLES: &[&str] = &[
+    "categories",
+    "words",
+    "settings",
+    "game_history",
+    "game_participants",
+    "game_word_results",
+    "scoring_presets",
+    "game_formats",
+    "word_merges",
+    "word_sets",
+    "word_set_words",
+];
+
+/// Copy the database behind `conn` to `dest` and verify the copy
+///
+/// The copy is written as a standalone (rollback journal) database file next
+/// to `dest`, checked with `PRAGMA integrity_check` and only then moved over
+/// `dest`, so a failed backup leaves an existing file at `dest` untouched.
+/// A manifest is written next to the backup.
+pub fn backup_to(conn: &Connection, dest: &Path) -> Result<BackupManifest, AppError> {
+    if conn.path().is_some_and(|source| Path::new(source) == dest) {
+        return Err(AppError::ValidationError(
+            "Yedek, kullanılan veritabanı dosyasının üzerine yazılamaz".to_string(),
+        ));
+    }
+
+    if let Some(parent) = dest
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut temp_name = dest.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = dest.with_file_name(temp_name);
+
+    // Start from an empty file so no stale pages or side files are left behind
+    for file in super::database_files(&temp_path) {
+        if file.exists() {
+            fs::remove_file(&file)?;
+        }
+    }
+
+    let result = copy_and_verify(conn, &temp_path).and_then(|manifest| {
+        // Side files of the old backup would be applied to the new one
+        for file in &super::database_files(dest)[1..] {
+            if file.exists() {
+                fs::remove_file(file)?;
+            }
+        }
+        fs::rename(&temp_path, dest)?;
+        Ok(manifest)
+    });
+
+    if result.is_err() {
+        for file in super::database_files(&temp_path) {
+            let _ = fs::remove_file(&file);
+        }
+    }
+
+    let manifest = result?;
+    fs::write(
+        manifest_path(dest),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+
+    Ok(manifest)
+}
+
+fn copy_and_verify(conn: &Connection, dest: &Path) -> Result<BackupManifest, AppError> {
+    let mut dest_conn = Connection::open(dest)?;
+
+    {
+        // The caller holds the only connection to the database, nothing can
+        // write between steps, so all pages are copied in a single step
+        let backup = Backup::new(conn, &mut dest_conn)?;
+        backup.run_to_completion(-1, Duration::ZERO, None)?;
+    }
+
+    // The source runs in WAL mode, store the copy as a single self-contained file
+    dest_conn.query_row("PRAGMA journal_mode = DELETE", [], |_| Ok(()))?;
+
+    verify_integrity(&dest_conn)?;
+
+    let mut manifest = build_manifest(&dest_conn)?;
+    manifest.app_version = Some(env!("CARGO_PKG_VERSION").to_string());
+    manifest.created_at = dest_conn.query_row("SELECT datetime('now')", [], |row| row.get(0))?;
+    drop(dest_conn);
+
+    manifest.size_bytes = fs::metadata(dest)?.len();
+
+    Ok(manifest)
+}
+
+/// Run `PRAGMA integrity_check` and fail unless it reports "ok"
+pub fn verify_integrity(conn: &Connection) -> Result<(), AppError> {
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let problems = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if problems.len() == 1 && problems[0] == "ok" {
+        return Ok(());
+    }
+
+    Err(AppError::DatabaseError(format!(
+        "Veritabanı bütünlük kontrolü başarısız: {}",
+        problems.join("; ")
+    )))
+}
+
+/// Describe the contents of a database (schema version and row counts)
+///
+/// `app_version`, `created_at` and `size_bytes` are left for the caller to fill in.
+pub fn build_manifest(conn: &Connection) -> Result<BackupManifest, AppError> {
+    let mut row_counts = Vec::new();
+
+    for table in BACKUP_TABLES {
+        let exists: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            [table],
+            |row| row.get(0),
+        )?;
+
+        if exists {
+            let rows = conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                row.get(0)
+            })?;
+            row_counts.push(TableRowCount {
+                table: (*table).to_string(),
+                rows,
+            });
+        }
+    }
+
+    Ok(BackupManifest {
+        app_version: None,
+        schema_version: get_schema_version(conn)?,
+        created_at: String::new(),
+        size_bytes: 0,
+        row_counts,
+    })
+}
+
+/// Path of the manifest file that belongs to a backup
+pub fn manifest_path(backup: &Path) -> PathBuf {
+    let mut path = backup.as_os_str().to_os_string();
+    path.push(".manifest.json");
+    PathBuf::from(path)
+}
+
+/// Read the manifest of a backup
+///
+/// Uses the manifest file when present. Older backups without one are
+/// inspected directly (read-only); their app version is reported as unknown.
+pub fn read_manifest(backup: &Path) -> Result<BackupManifest, AppError> {
+    if !backup.exists() {
+        return Err(AppError::NotFoundError(format!(
+            "Yedek dosyası bulunamadı: {}",
+            backup.display()
+        )));
+    }
+
+    let manifest_file = manifest_path(backup);
+    if manifest_file.exists() {
+        let content = fs::read_to_string(&manifest_file)?;
+        return Ok(serde_json::from_str(&content)?);
+    }
+
+    let conn = Connection::open_with_flags(backup, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let mut manifest = build_manifest(&conn)?;
+
+    let metadata = fs::metadata(backup)?;
+    manifest.size_bytes = metadata.len();
+
+    let modified_secs = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_secs());
+    manifest.created_at = conn.query_row(
+        "SELECT datetime(?1, 'unixepoch')",
+        [i64::try_from(modified_secs).unwrap_or(0)],
+        |row| row.get(0),
+    )?;
+
+    Ok(manifest)
+}
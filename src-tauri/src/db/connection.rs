@@ -1,54 +1,209 @@
 //! Database connection management
 //!
-//! Handles database file location and connection initialization
+//! Handles database file location and connection initialization.
+//! A single connection is opened on startup and shared between all
+//! commands through Tauri managed state (`DbState`).
 
+use super::profiles::{profile_db_path, ProfileRegistry};
+use crate::errors::AppError;
 use rusqlite::{Connection, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
 
-/// Get the database file path in the user's application data directory
+/// How long a statement waits for a lock held by another connection
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Number of prepared statements kept in the per-connection cache
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
+/// Environment variable that overrides the database file location
+pub const DB_PATH_ENV: &str = "BIL_BAKALIM_DB_PATH";
+
+/// Command line flag that overrides the database file location (`--db-path <file>`)
+pub const DB_PATH_ARG: &str = "--db-path";
+
+/// Shared database connection registered as Tauri managed state
 ///
-/// Platform-specific locations:
+/// Commands receive it as `State<'_, DbState>` and lock it for the
+/// duration of the command via `DbState::conn`. The connection can be
+/// swapped at runtime (e.g. when switching profiles) with `DbState::reopen`.
+pub struct DbState {
+    conn: Mutex<Connection>,
+    path: Mutex<PathBuf>,
+}
+
+impl DbState {
+    /// Open, migrate and seed the database at the given path
+    pub fn open(path: PathBuf) -> Result<Self, AppError> {
+        let conn = init_connection(&path)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            path: Mutex::new(path),
+        })
+    }
+
+    /// Lock the shared connection
+    pub fn conn(&self) -> Result<MutexGuard<'_, Connection>, AppError> {
+        self.conn.lock().map_err(|_| {
+            AppError::DatabaseError("Veritabanı bağlantısı kullanılamıyor".to_string())
+        })
+    }
+
+    /// Path of the database file currently in use
+    pub fn path(&self) -> Result<PathBuf, AppError> {
+        self.path.lock().map(|path| path.clone()).map_err(|_| {
+            AppError::DatabaseError("Veritabanı bağlantısı kullanılamıyor".to_string())
+        })
+    }
+
+    /// Replace the shared connection with one opened at `path`
+    ///
+    /// The new database is migrated and seeded before the swap, so a failure
+    /// leaves the current connection untouched.
+    pub fn reopen(&self, path: PathBuf) -> Result<(), AppError> {
+        let new_conn = init_connection(&path)?;
+
+        let mut conn = self.conn()?;
+        *conn = new_conn;
+
+        let mut current_path = self.path.lock().map_err(|_| {
+            AppError::DatabaseError("Veritabanı bağlantısı kullanılamıyor".to_string())
+        })?;
+        *current_path = path;
+
+        Ok(())
+    }
+
+    /// Replace the database file in use with `replacement` and reopen it
+    ///
+    /// The current connection is closed first so the file can be renamed over
+    /// on every platform. If reopening fails the state is left on an in-memory
+    /// placeholder connection and the caller is expected to swap in a known-good
+    /// file (see `db::restore`).
+    pub fn swap_file(&self, replacement: &Path) -> Result<(), AppError> {
+        let path = self.path()?;
+        let mut conn = self.conn()?;
+
+        // Park the state on an in-memory database while the file is replaced
+        let current = std::mem::replace(&mut *conn, Connection::open_in_memory()?);
+        current.close().map_err(|(_, e)| e)?;
+
+        for side_file in &database_files(&path)[1..] {
+            if side_file.exists() {
+                std::fs::remove_file(side_file)?;
+            }
+        }
+        std::fs::rename(replacement, &path)?;
+
+        *conn = init_connection(&path)?;
+
+        Ok(())
+    }
+}
+
+/// Database path given on the command line or through the environment
+///
+/// The command line flag takes precedence over the environment variable.
+pub fn db_path_override() -> Option<PathBuf> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == DB_PATH_ARG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(value) = arg.strip_prefix("--db-path=") {
+            return Some(PathBuf::from(value));
+        }
+    }
+
+    std::env::var_os(DB_PATH_ENV)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Get the path of the database file to use
+///
+/// Resolution order:
+/// 1. `--db-path` command line flag or `BIL_BAKALIM_DB_PATH` environment variable
+/// 2. Database file of the active profile (see `db::profiles`)
+///
+/// The default profile lives in the user's application data directory:
 /// - Windows: `%APPDATA%\com.bilbakalim.app\word-game.db`
 /// - macOS: `~/Library/Application Support/com.bilbakalim.app/word-game.db`
 /// - Linux: `~/.local/share/com.bilbakalim.app/word-game.db`
 pub fn get_db_path() -> Result<PathBuf, String> {
-    let app_dir =
-        dirs::data_dir().ok_or_else(|| "Could not determine user data directory".to_string())?;
+    if let Some(path) = db_path_override() {
+        // Create directory if it doesn't exist
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create database directory: {}", e))?;
+        }
+        return Ok(path);
+    }
 
-    let db_dir = app_dir.join("com.bilbakalim.app");
+    let registry = ProfileRegistry::load().map_err(|e| e.to_string())?;
+    let profile = registry.active_profile().map_err(|e| e.to_string())?;
 
-    // Create directory if it doesn't exist
-    std::fs::create_dir_all(&db_dir)
-        .map_err(|e| format!("Failed to create database directory: {}", e))?;
+    profile_db_path(profile).map_err(|e| e.to_string())
+}
 
-    Ok(db_dir.join("word-game.db"))
+/// The database file together with its WAL and shared-memory side files
+pub fn database_files(db_path: &Path) -> [PathBuf; 3] {
+    let with_suffix = |suffix: &str| {
+        let mut file = db_path.as_os_str().to_os_string();
+        file.push(suffix);
+        PathBuf::from(file)
+    };
+
+    [
+        db_path.to_path_buf(),
+        with_suffix("-wal"),
+        with_suffix("-shm"),
+    ]
 }
 
-/// Get a database connection with foreign keys enabled
+/// Open a configured database connection at the given path
 ///
 /// This function:
-/// 1. Determines the platform-specific database path
-/// 2. Opens or creates the database file
-/// 3. Enables foreign key constraints (PRAGMA foreign_keys = ON)
-pub fn get_connection() -> Result<Connection> {
-    let db_path = get_db_path().map_err(|e| rusqlite::Error::InvalidPath(PathBuf::from(e)))?;
-
-    let conn = Connection::open(&db_path)?;
+/// 1. Opens or creates the database file
+/// 2. Enables foreign key constraints (PRAGMA foreign_keys = ON)
+/// 3. Switches to WAL journaling so readers don't block the writer
+/// 4. Sets a busy timeout and the prepared statement cache size
+pub fn open_connection(db_path: &Path) -> Result<Connection> {
+    let conn = Connection::open(db_path)?;
 
     // Enable foreign key constraints
     conn.execute("PRAGMA foreign_keys = ON", [])?;
 
+    // journal_mode returns the resulting mode as a row
+    conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
+
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+
     Ok(conn)
 }
 
-/// Initialize the database by running all migrations and seeding
+/// Open a connection at `db_path` and bring it up to date
 ///
-/// This function should be called on application startup to ensure:
-/// 1. The database schema is created and up-to-date
-/// 2. Default data is seeded (only on first launch)
-pub fn init_database() -> Result<()> {
-    let conn = get_connection()?;
-    super::schema::run_migrations(&conn)?;
+/// Runs all pending migrations and seeds default data (only on first launch).
+/// Fails if the database was written by a newer version of the application.
+pub fn init_connection(db_path: &Path) -> Result<Connection, AppError> {
+    let conn = open_connection(db_path)?;
+    super::migrations::run_migrations(&conn)?;
     super::seed::seed_database(&conn)?;
-    Ok(())
+    Ok(conn)
+}
+
+/// Initialize the database on application startup
+///
+/// Resolves the database path, runs all migrations and seeding, and returns
+/// the shared connection state to register with Tauri.
+pub fn init_database() -> Result<DbState, AppError> {
+    let db_path = get_db_path().map_err(AppError::FileSystemError)?;
+    DbState::open(db_path)
 }
@@ -0,0 +1,663 @@
+//! Versioned schema migrations
+//!
+//! Migrations are registered in `MIGRATIONS` in ascending version order and are
+//! tracked with SQLite's `PRAGMA user_version`. On startup every migration with a
+//! version greater than the stored one is applied, each in its own transaction,
+//! and `user_version` is bumped inside the same transaction.
+//!
+//! Rules for adding a migration:
+//! - Append a new entry with the next version number (never renumber or edit old ones)
+//! - Migrations are forward-only, there is no down step
+//! - A database with a version newer than `LATEST_VERSION` is refused
+//! - Write the SQL (and any seed rows or text handling) into the migration
+//!   itself instead of calling code that may change later, so a migration does
+//!   the same thing on every database it ever runs on
+
+use crate::errors::AppError;
+use rusqlite::{params, Connection, Result};
+use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Turkish alphabet plus the circumflexed vowels, as of migration 5
+const TURKISH_LETTERS: &str = "ABCÇDEFGĞHIİJKLMNOÖPRSŞTUÜVYZÂÎÛ";
+
+/// English alphabet, as of migration 5
+const ENGLISH_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A single forward-only schema migration
+pub struct Migration {
+    /// Version the database is at after this migration is applied
+    pub version: i32,
+    /// Short human readable description (used in logs and errors)
+    pub description: &'static str,
+    /// Migration body, executed inside a transaction
+    pub up: fn(&Connection) -> Result<()>,
+}
+
+/// Ordered migration registry
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Initial schema",
+        up: migrate_initial_schema,
+    },
+    Migration {
+        version: 2,
+        description: "Add elapsed_time_seconds to game_participants",
+        up: migrate_add_elapsed_time,
+    },
+    Migration {
+        version: 3,
+        description: "Add scoring presets",
+        up: migrate_add_scoring_presets,
+    },
+    Migration {
+        version: 4,
+        description: "Add game formats and drop the 4-10 letter limit on words",
+        up: migrate_add_game_formats,
+    },
+    Migration {
+        version: 5,
+        description: "Add alphabet whitelist to categories",
+        up: migrate_add_category_alphabet,
+    },
+    Migration {
+        version: 6,
+        description: "Add language to categories and game history",
+        up: migrate_add_language,
+    },
+    Migration {
+        version: 7,
+        description: "Merge duplicate words and make words unique per category",
+        up: migrate_unique_words,
+    },
+    Migration {
+        version: 8,
+        description: "Link game word results to words",
+        up: migrate_link_word_results,
+    },
+    Migration {
+        version: 9,
+        description: "Record how the words of a game were drawn",
+        up: migrate_add_word_draw,
+    },
+    Migration {
+        version: 10,
+        description: "Add word sets",
+        up: migrate_add_word_sets,
+    },
+];
+
+/// Schema version this binary expects
+pub const LATEST_VERSION: i32 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+/// Read the schema version stored in the database header
+pub fn get_schema_version(conn: &Connection) -> Result<i32> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+}
+
+/// Apply all pending migrations
+///
+/// Each migration runs in its own transaction together with the
+/// `user_version` update, so a failed step leaves the database at the
+/// last successfully applied version.
+pub fn run_migrations(conn: &Connection) -> Result<(), AppError> {
+    let current_version = get_schema_version(conn)?;
+
+    if current_version > LATEST_VERSION {
+        return Err(AppError::DatabaseError(format!(
+            "Veritabanı sürümü ({}) bu uygulamanın desteklediği sürümden ({}) daha yeni. Lütfen uygulamayı güncelleyin.",
+            current_version, LATEST_VERSION
+        )));
+    }
+
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.version > current_version)
+    {
+        apply_migration(conn, migration).map_err(|e| {
+            AppError::DatabaseError(format!(
+                "Migration {} ({}) başarısız: {}",
+                migration.version, migration.description, e
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Apply a single migration and record its version
+fn apply_migration(conn: &Connection, migration: &Migration) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+
+    (migration.up)(&tx)?;
+    tx.pragma_update(None, "user_version", migration.version)?;
+
+    tx.commit()
+}
+
+/// Migration 1: Create the initial tables and indexes
+///
+/// Every statement is idempotent so databases created before versioning was
+/// introduced are adopted as-is.
+fn migrate_initial_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS categories (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            emoji TEXT,
+            description TEXT,
+            is_default BOOLEAN DEFAULT 0,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE TABLE IF NOT EXISTS words (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            category_id INTEGER NOT NULL,
+            word TEXT NOT NULL,
+            letter_count INTEGER NOT NULL CHECK (letter_count BETWEEN 4 AND 10),
+            hint TEXT NOT NULL,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE CASCADE
+        );
+        CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT
+        );
+        CREATE TABLE IF NOT EXISTS game_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            category_id INTEGER,
+            category_name TEXT NOT NULL,
+            game_mode TEXT NOT NULL,
+            played_at DATETIME NOT NULL,
+            total_time_seconds INTEGER,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE SET NULL
+        );
+        CREATE TABLE IF NOT EXISTS game_participants (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            game_history_id INTEGER NOT NULL,
+            participant_name TEXT NOT NULL,
+            participant_type TEXT NOT NULL,
+            score INTEGER DEFAULT 0,
+            words_found INTEGER DEFAULT 0,
+            words_skipped INTEGER DEFAULT 0,
+            letters_revealed INTEGER DEFAULT 0,
+            elapsed_time_seconds INTEGER,
+            rank INTEGER,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (game_history_id) REFERENCES game_history(id) ON DELETE CASCADE
+        );
+        CREATE TABLE IF NOT EXISTS game_word_results (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            game_history_id INTEGER NOT NULL,
+            participant_id INTEGER,
+            word TEXT NOT NULL,
+            word_hint TEXT,
+            result TEXT NOT NULL,
+            points_earned INTEGER DEFAULT 0,
+            letters_used INTEGER DEFAULT 0,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (game_history_id) REFERENCES game_history(id) ON DELETE CASCADE,
+            FOREIGN KEY (participant_id) REFERENCES game_participants(id) ON DELETE SET NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_words_category ON words(category_id);
+        CREATE INDEX IF NOT EXISTS idx_words_letter_count ON words(letter_count);
+        CREATE INDEX IF NOT EXISTS idx_game_history_played_at ON game_history(played_at);
+        CREATE INDEX IF NOT EXISTS idx_game_history_category ON game_history(category_id);
+        CREATE INDEX IF NOT EXISTS idx_game_participants_game
+            ON game_participants(game_history_id);
+        CREATE INDEX IF NOT EXISTS idx_game_word_results_game
+            ON game_word_results(game_history_id);",
+    )
+}
+
+/// Migration 2: Add elapsed_time_seconds column to game_participants
+///
+/// Databases created before versioning may already have the column,
+/// so the column is only added when missing.
+fn migrate_add_elapsed_time(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "game_participants", "elapsed_time_seconds")? {
+        conn.execute(
+            "ALTER TABLE game_participants ADD COLUMN elapsed_time_seconds INTEGER",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// Migration 3: Add scoring_presets and reference the preset from game_history
+///
+/// Built-in presets are inserted here so existing databases get them too.
+fn migrate_add_scoring_presets(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS scoring_presets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            points_per_letter INTEGER NOT NULL,
+            wrong_penalty_factor REAL NOT NULL,
+            timeout_penalty_factor REAL NOT NULL,
+            no_reveal_bonus INTEGER NOT NULL DEFAULT 0,
+            time_bonus_per_second INTEGER NOT NULL DEFAULT 0,
+            is_default BOOLEAN DEFAULT 0,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        );
+        INSERT OR IGNORE INTO scoring_presets
+            (name, points_per_letter, wrong_penalty_factor, timeout_penalty_factor,
+             no_reveal_bonus, time_bonus_per_second, is_default)
+        VALUES ('Standart', 100, 1.0, 1.0, 0, 0, 1),
+               ('Eksi Puan Yok', 100, 0.0, 0.0, 0, 0, 1),
+               ('Yarışma', 100, 1.5, 1.5, 200, 2, 1);",
+    )?;
+
+    if !column_exists(conn, "game_history", "scoring_preset_id")? {
+        conn.execute(
+            "ALTER TABLE game_history ADD COLUMN scoring_preset_id INTEGER
+             REFERENCES scoring_presets(id) ON DELETE SET NULL",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// Migration 4: Add game_formats and rebuild words without the length CHECK
+///
+/// SQLite cannot drop a CHECK constraint, so the words table is copied into a
+/// new table (keeping IDs) and swapped in. Nothing references words yet.
+fn migrate_add_game_formats(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS game_formats (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            min_length INTEGER NOT NULL,
+            max_length INTEGER NOT NULL,
+            words_per_length INTEGER NOT NULL,
+            total_words INTEGER NOT NULL,
+            word_order TEXT NOT NULL DEFAULT 'ascending',
+            is_default BOOLEAN DEFAULT 0,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        );
+        INSERT OR IGNORE INTO game_formats
+            (name, min_length, max_length, words_per_length, total_words, word_order, is_default)
+        VALUES ('Standart', 4, 10, 2, 14, 'ascending', 1),
+               ('İlkokul', 3, 7, 2, 10, 'ascending', 1),
+               ('Uzun', 5, 12, 2, 16, 'ascending', 1);
+        CREATE TABLE words_new (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            category_id INTEGER NOT NULL,
+            word TEXT NOT NULL,
+            letter_count INTEGER NOT NULL CHECK (letter_count > 0),
+            hint TEXT NOT NULL,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE CASCADE
+        );
+        INSERT INTO words_new (id, category_id, word, letter_count, hint, created_at)
+            SELECT id, category_id, word, letter_count, hint, created_at FROM words;
+        DROP TABLE words;
+        ALTER TABLE words_new RENAME TO words;
+        CREATE INDEX IF NOT EXISTS idx_words_category ON words(category_id);
+        CREATE INDEX IF NOT EXISTS idx_words_letter_count ON words(letter_count);",
+    )
+}
+
+/// Migration 5: Add alphabet and custom_letters to categories
+///
+/// Existing categories get the first alphabet that accepts all their words
+/// (Turkish, then English). Categories matching neither get a custom alphabet
+/// of the Turkish letters plus the extra letters their words use (see
+/// `custom_alphabet`).
+fn migrate_add_category_alphabet(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "categories", "alphabet")? {
+        conn.execute(
+            "ALTER TABLE categories ADD COLUMN alphabet TEXT NOT NULL DEFAULT 'turkish'",
+            [],
+        )?;
+    }
+    if !column_exists(conn, "categories", "custom_letters")? {
+        conn.execute("ALTER TABLE categories ADD COLUMN custom_letters TEXT", [])?;
+    }
+
+    let category_ids: Vec<i32> = conn
+        .prepare("SELECT id FROM categories")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_>>()?;
+
+    for category_id in category_ids {
+        let words: Vec<String> = conn
+            .prepare("SELECT word FROM words WHERE category_id = ?1")?
+            .query_map([category_id], |row| row.get(0))?
+            .collect::<Result<_>>()?;
+
+        let fits = |letters| words.iter().all(|word| fits_letters(word, letters));
+
+        let (alphabet, custom_letters) = if fits(TURKISH_LETTERS) {
+            ("turkish", None)
+        } else if fits(ENGLISH_LETTERS) {
+            ("english", None)
+        } else {
+            ("custom", Some(custom_alphabet(&words)))
+        };
+
+        conn.execute(
+            "UPDATE categories SET alphabet = ?1, custom_letters = ?2 WHERE id = ?3",
+            params![alphabet, custom_letters, category_id],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Migration 6: Add language to categories and game_history
+///
+/// Categories using the English alphabet become English, everything else
+/// Turkish. Past games take the language of their category.
+fn migrate_add_language(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "categories", "language")? {
+        conn.execute(
+            "ALTER TABLE categories ADD COLUMN language TEXT NOT NULL DEFAULT 'tr'",
+            [],
+        )?;
+        conn.execute(
+            "UPDATE categories SET language = 'en' WHERE alphabet = 'english'",
+            [],
+        )?;
+    }
+
+    if !column_exists(conn, "game_history", "language")? {
+        conn.execute(
+            "ALTER TABLE game_history ADD COLUMN language TEXT NOT NULL DEFAULT 'tr'",
+            [],
+        )?;
+        conn.execute(
+            "UPDATE game_history SET language = COALESCE(
+                 (SELECT c.language FROM categories c WHERE c.id = game_history.category_id),
+                 'tr'
+             )",
+            [],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Migration 7: Add words.normalized_word and a unique (category_id, normalized_word) index
+///
+/// Words are compared with the key of their category's language (see
+/// `word_key`). Duplicates are merged into the oldest word (lowest ID), which takes over
+/// the hint of a removed duplicate when its own is empty. Each removed word is
+/// recorded in word_merges so the merge can be reported to the user.
+fn migrate_unique_words(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "words", "normalized_word")? {
+        conn.execute(
+            "ALTER TABLE words ADD COLUMN normalized_word TEXT NOT NULL DEFAULT ''",
+            [],
+        )?;
+    }
+    // No foreign keys, the merge report outlives the words
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS word_merges (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            category_id INTEGER NOT NULL,
+            kept_word_id INTEGER NOT NULL,
+            removed_word_id INTEGER NOT NULL,
+            word TEXT NOT NULL,
+            hint TEXT NOT NULL,
+            merged_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )?;
+
+    let categories: Vec<(i32, String)> = conn
+        .prepare(
+            "SELECT DISTINCT w.category_id, COALESCE(c.language, 'tr')
+             FROM words w
+             LEFT JOIN categories c ON c.id = w.category_id",
+        )?
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<_>>()?;
+
+    for (category_id, language) in categories {
+        let words: Vec<(i32, String, String)> = conn
+            .prepare("SELECT id, word, hint FROM words WHERE category_id = ?1 ORDER BY id")?
+            .query_map([category_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .collect::<Result<_>>()?;
+
+        // Collation key -> (kept word ID, kept word has a hint)
+        let mut kept: HashMap<String, (i32, bool)> = HashMap::new();
+        for (id, word, hint) in words {
+            let key = word_key(&word, &language);
+            let Some(&mut (kept_id, ref mut has_hint)) = kept.get_mut(&key) else {
+                conn.execute(
+                    "UPDATE words SET normalized_word = ?1 WHERE id = ?2",
+                    params![key, id],
+                )?;
+                kept.insert(key, (id, !hint.trim().is_empty()));
+                continue;
+            };
+
+            if !*has_hint && !hint.trim().is_empty() {
+                conn.execute(
+                    "UPDATE words SET hint = ?1 WHERE id = ?2",
+                    params![hint, kept_id],
+                )?;
+                *has_hint = true;
+            }
+            conn.execute(
+                "INSERT INTO word_merges (category_id, kept_word_id, removed_word_id, word, hint)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![category_id, kept_id, id, word, hint],
+            )?;
+            conn.execute("DELETE FROM words WHERE id = ?1", [id])?;
+        }
+    }
+
+    conn.execute(
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx_words_category_normalized
+         ON words(category_id, normalized_word)",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Migration 8: Add `game_word_results.word_id`
+///
+/// Past results only stored the word text. They are linked by the key of
+/// the game's language (see `word_key`) within the game's category, which also points
+/// results of words merged by migration 7 at the kept word. Results whose
+/// word has since been deleted stay unlinked.
+fn migrate_link_word_results(conn: &Connection) -> Result<()> {
+    if !column_exists(conn, "game_word_results", "word_id")? {
+        conn.execute(
+            "ALTER TABLE game_word_results
+             ADD COLUMN word_id INTEGER REFERENCES words(id) ON DELETE SET NULL",
+            [],
+        )?;
+    }
+
+    let results: Vec<(i32, String, i32, String)> = conn
+        .prepare(
+            "SELECT r.id, r.word, gh.category_id, gh.language
+             FROM game_word_results r
+             JOIN game_history gh ON gh.id = r.game_history_id
+             WHERE r.word_id IS NULL AND gh.category_id IS NOT NULL",
+        )?
+        .query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<Result<_>>()?;
+
+    for (id, word, category_id, language) in results {
+        let key = word_key(&word, &language);
+        conn.execute(
+            "UPDATE game_word_results
+             SET word_id = (SELECT id FROM words WHERE category_id = ?1 AND normalized_word = ?2)
+             WHERE id = ?3
+               AND EXISTS (SELECT 1 FROM words WHERE category_id = ?1 AND normalized_word = ?2)",
+            params![category_id, key, id],
+        )?;
+    }
+
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_game_word_results_word
+         ON game_word_results(word_id)",
+        [],
+    )?;
+    Ok(())
+}
+
+/// Migration 9: Add seed, game_format_id and word_selection to game_history
+///
+/// Past games have no seed and cannot be drawn again.
+fn migrate_add_word_draw(conn: &Connection) -> Result<()> {
+    let columns = [
+        ("seed", "INTEGER"),
+        (
+            "game_format_id",
+            "INTEGER REFERENCES game_formats(id) ON DELETE SET NULL",
+        ),
+        ("word_selection", "TEXT"),
+    ];
+
+    for (column, definition) in columns {
+        if !column_exists(conn, "game_history", column)? {
+            conn.execute(
+                &format!(
+                    "ALTER TABLE game_history ADD COLUMN {} {}",
+                    column, definition
+                ),
+                [],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Migration 10: Add word sets and record the set a game was played with
+///
+/// The set's name is copied into game_history like the category name, so
+/// history keeps it after the set is deleted.
+fn migrate_add_word_sets(conn: &Connection) -> Result<()> {
+    // Deleting a word removes it from its sets
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS word_sets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            description TEXT,
+            game_format_id INTEGER REFERENCES game_formats(id) ON DELETE SET NULL,
+            created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+            updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE TABLE IF NOT EXISTS word_set_words (
+            word_set_id INTEGER NOT NULL REFERENCES word_sets(id) ON DELETE CASCADE,
+            word_id INTEGER NOT NULL REFERENCES words(id) ON DELETE CASCADE,
+            position INTEGER NOT NULL,
+            PRIMARY KEY (word_set_id, word_id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_word_set_words_word ON word_set_words(word_id);",
+    )?;
+
+    if !column_exists(conn, "game_history", "word_set_id")? {
+        conn.execute(
+            "ALTER TABLE game_history ADD COLUMN word_set_id INTEGER
+             REFERENCES word_sets(id) ON DELETE SET NULL",
+            [],
+        )?;
+    }
+    if !column_exists(conn, "game_history", "word_set_name")? {
+        conn.execute("ALTER TABLE game_history ADD COLUMN word_set_name TEXT", [])?;
+    }
+
+    Ok(())
+}
+
+/// Whether every letter (grapheme) of a stored word is one of `letters`
+///
+/// Copy of `text::check_letters` as of migration 5.
+fn fits_letters(word: &str, letters: &str) -> bool {
+    word.graphemes(true)
+        .all(|letter| letters.graphemes(true).any(|allowed| allowed == letter))
+}
+
+/// Custom alphabet of the Turkish letters plus the letters of `words`
+///
+/// Copy of `text::parse_custom_letters` with Turkish casing as of migration
+/// 5: the text is NFC-normalized and upper-cased (i → İ, ı → I), separators
+/// are dropped and duplicates removed. Falls back to the Turkish letters
+/// when a word holds something other than letters.
+fn custom_alphabet(words: &[String]) -> String {
+    let mut text = TURKISH_LETTERS.to_string();
+    text.extend(
+        words
+            .iter()
+            .map(|word| word.trim().nfc().collect::<String>()),
+    );
+    let text: String = text.trim().nfc().collect();
+
+    let mut upper = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'i' => upper.push('İ'),
+            'ı' => upper.push('I'),
+            // "i" followed by a combining dot above is already "İ"
+            '\u{307}' if upper.ends_with('İ') => {}
+            _ => upper.extend(c.to_uppercase()),
+        }
+    }
+    let upper: String = upper.nfc().collect();
+
+    let mut letters: Vec<&str> = Vec::new();
+    for letter in upper.graphemes(true) {
+        if letter.chars().all(|c| c.is_whitespace() || c == ',') {
+            continue;
+        }
+        if !letter.chars().next().is_some_and(char::is_alphabetic) {
+            return TURKISH_LETTERS.to_string();
+        }
+        if !letters.contains(&letter) {
+            letters.push(letter);
+        }
+    }
+
+    letters.concat()
+}
+
+/// Duplicate key of a word as defined by migration 7 (also used by migration 8)
+///
+/// Copy of `text::collation_key` at that version: NFC-normalized and
+/// lower-cased with the language's rules; Turkish ("tr" or unknown) maps
+/// I → ı, İ → i and ignores the circumflex.
+fn word_key(word: &str, language: &str) -> String {
+    let word: String = word.trim().nfc().collect();
+    if language == "en" {
+        return word.to_lowercase();
+    }
+
+    let mut key = String::with_capacity(word.len());
+    for c in word.chars() {
+        match c {
+            'I' => key.push('ı'),
+            'İ' => key.push('i'),
+            _ => key.extend(c.to_lowercase().map(|c| match c {
+                'â' => 'a',
+                'î' => 'i',
+                'û' => 'u',
+                _ => c,
+            })),
+        }
+    }
+
+    key
+}
+
+/// Check whether a column exists on a table
+pub(crate) fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+        [table, column],
+        |row| row.get::<_, i32>(0).map(|count| count > 0),
+    )
+}
@@ -1,20 +1,35 @@
 //! Database module for Bil Bakalım
 //!
 //! This module handles all SQLite database operations including:
-//! - Database initialization and migrations
+//! - Database initialization and versioned migrations
 //! - Connection management
-//! - Schema definitions
+//! - Online backups with integrity verification
+//! - Scheduled automatic backups with retention
+//! - Validated restore with automatic rollback
+//! - Profiles (one database file per classroom)
 //!
-//! Database Schema:
-//! - categories: Word categories with emoji and metadata
-//! - words: Words associated with categories (4-10 letters)
+//! Database Schema (tables are created and changed by `migrations`):
+//! - categories: Word categories (alphabet added in migration 5, language in migration 6)
+//! - words: Words with hints (normalized_word added in migration 7)
 //! - settings: Key-value application settings
-//! - game_history: Historical game sessions
+//! - game_history: Historical game sessions (seed, game_format_id and word_selection added in migration 9,
+//!   word_set_id and word_set_name in migration 10)
 //! - game_participants: Players/teams in each game
-//! - game_word_results: Detailed word-by-word game results
+//! - game_word_results: Word-by-word results for each participant (word_id added in migration 8)
+//! - scoring_presets: Named scoring rules (added in migration 3)
+//! - game_formats: Named word-length ladders (added in migration 4)
+//! - word_merges: Duplicate words merged by migration 7
+//! - word_sets, word_set_words: Curated, ordered word sets (added in migration 10)
 
+pub mod auto_backup;
+pub mod backup;
 pub mod connection;
-pub mod schema;
+pub mod migrations;
+pub mod profiles;
+pub mod restore;
 pub mod seed;
 
-pub use connection::{get_connection, get_db_path, init_database};
+pub use connection::{
+    database_files, db_path_override, get_db_path, init_connection, init_database, open_connection,
+    DbState,
+};
@@ -0,0 +1,253 @@
+//! Database profiles ("classrooms")
+//!
+//! Each profile has its own database file. The registry of profiles and the
+//! currently active profile are stored in `profiles.json` in the application
+//! data directory. The default profile keeps using `word-game.db` so existing
+//! installations are picked up without any migration.
+
+use crate::errors::AppError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// ID of the built-in profile that always exists
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// Registry file name inside the application data directory
+const REGISTRY_FILE: &str = "profiles.json";
+
+/// Directory (inside the application data directory) holding profile databases
+const PROFILES_DIR: &str = "profiles";
+
+/// A single profile entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profile {
+    pub id: String,
+    pub name: String,
+    /// Database file path relative to the application data directory
+    pub db_file: String,
+}
+
+/// Persisted list of profiles
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProfileRegistry {
+    pub active: String,
+    pub profiles: Vec<Profile>,
+}
+
+impl Default for ProfileRegistry {
+    fn default() -> Self {
+        Self {
+            active: DEFAULT_PROFILE_ID.to_string(),
+            profiles: vec![Profile {
+                id: DEFAULT_PROFILE_ID.to_string(),
+                name: "Varsayılan".to_string(),
+                db_file: "word-game.db".to_string(),
+            }],
+        }
+    }
+}
+
+impl ProfileRegistry {
+    /// Load the registry, falling back to the default registry if none was saved yet
+    pub fn load() -> Result<Self, AppError> {
+        let path = registry_path()?;
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let mut registry: Self = serde_json::from_str(&content)?;
+
+        // The default profile can never be removed, restore it if the file was edited
+        if registry.find(DEFAULT_PROFILE_ID).is_none() {
+            registry
+                .profiles
+                .insert(0, Self::default().profiles.remove(0));
+        }
+        if registry.find(&registry.active).is_none() {
+            registry.active = DEFAULT_PROFILE_ID.to_string();
+        }
+
+        Ok(registry)
+    }
+
+    /// Save the registry (written to a temporary file first, then renamed)
+    pub fn save(&self) -> Result<(), AppError> {
+        let path = registry_path()?;
+        let tmp_path = path.with_extension("json.tmp");
+
+        fs::write(&tmp_path, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&tmp_path, &path)?;
+
+        Ok(())
+    }
+
+    /// Find a profile by ID
+    pub fn find(&self, id: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.id == id)
+    }
+
+    /// Find a profile by ID or return a not found error
+    pub fn get(&self, id: &str) -> Result<&Profile, AppError> {
+        self.find(id)
+            .ok_or_else(|| AppError::NotFoundError(format!("Profil bulunamadı: {}", id)))
+    }
+
+    /// The currently active profile
+    pub fn active_profile(&self) -> Result<&Profile, AppError> {
+        self.get(&self.active)
+    }
+
+    /// Add a new profile with a database file derived from its name
+    pub fn create(&mut self, name: &str) -> Result<Profile, AppError> {
+        let name = validate_profile_name(self, name, None)?;
+
+        let base_id = slugify(&name);
+        let mut id = base_id.clone();
+        let mut suffix = 2;
+        while self.find(&id).is_some() {
+            id = format!("{}-{}", base_id, suffix);
+            suffix += 1;
+        }
+
+        let profile = Profile {
+            db_file: format!("{}/{}.db", PROFILES_DIR, id),
+            id,
+            name,
+        };
+        self.profiles.push(profile.clone());
+
+        Ok(profile)
+    }
+
+    /// Change the display name of a profile (the database file is kept)
+    pub fn rename(&mut self, id: &str, name: &str) -> Result<Profile, AppError> {
+        let name = validate_profile_name(self, name, Some(id))?;
+
+        let profile = self
+            .profiles
+            .iter_mut()
+            .find(|profile| profile.id == id)
+            .ok_or_else(|| AppError::NotFoundError(format!("Profil bulunamadı: {}", id)))?;
+        profile.name = name;
+
+        Ok(profile.clone())
+    }
+
+    /// Remove a profile from the registry
+    ///
+    /// The default and the active profile cannot be removed.
+    pub fn remove(&mut self, id: &str) -> Result<Profile, AppError> {
+        if id == DEFAULT_PROFILE_ID {
+            return Err(AppError::ValidationError(
+                "Varsayılan profil silinemez".to_string(),
+            ));
+        }
+        if id == self.active {
+            return Err(AppError::ValidationError(
+                "Aktif profil silinemez, önce başka bir profile geçin".to_string(),
+            ));
+        }
+
+        let index = self
+            .profiles
+            .iter()
+            .position(|profile| profile.id == id)
+            .ok_or_else(|| AppError::NotFoundError(format!("Profil bulunamadı: {}", id)))?;
+
+        Ok(self.profiles.remove(index))
+    }
+}
+
+/// Get the application data directory, creating it if needed
+pub fn get_data_dir() -> Result<PathBuf, AppError> {
+    let app_dir = dirs::data_dir()
+        .ok_or_else(|| {
+            AppError::FileSystemError("Could not determine user data directory".to_string())
+        })?
+        .join("com.bilbakalim.app");
+
+    fs::create_dir_all(&app_dir)?;
+
+    Ok(app_dir)
+}
+
+/// Absolute database path of a profile, creating its directory if needed
+pub fn profile_db_path(profile: &Profile) -> Result<PathBuf, AppError> {
+    let path = get_data_dir()?.join(&profile.db_file);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    Ok(path)
+}
+
+fn registry_path() -> Result<PathBuf, AppError> {
+    Ok(get_data_dir()?.join(REGISTRY_FILE))
+}
+
+/// Trim and validate a profile name (non-empty, unique)
+fn validate_profile_name(
+    registry: &ProfileRegistry,
+    name: &str,
+    current_id: Option<&str>,
+) -> Result<String, AppError> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        return Err(AppError::ValidationError(
+            "Profil adı boş olamaz".to_string(),
+        ));
+    }
+
+    let taken = registry
+        .profiles
+        .iter()
+        .any(|profile| Some(profile.id.as_str()) != current_id && profile.name == name);
+    if taken {
+        return Err(AppError::DuplicateError(format!(
+            "Bu isimde bir profil zaten var: {}",
+            name
+        )));
+    }
+
+    Ok(name.to_string())
+}
+
+/// Build a file-system friendly ID from a profile name
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+
+    for c in name.chars().flat_map(char::to_lowercase) {
+        // 'İ' lowercases to 'i' followed by a combining dot above
+        if c == '\u{307}' {
+            continue;
+        }
+
+        let c = match c {
+            'ç' => 'c',
+            'ğ' => 'g',
+            'ı' => 'i',
+            'ö' => 'o',
+            'ş' => 's',
+            'ü' => 'u',
+            _ => c,
+        };
+
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() || slug == DEFAULT_PROFILE_ID {
+        "profil".to_string()
+    } else {
+        slug.to_string()
+    }
+}
@@ -0,0 +1,158 @@
+//! Safe database restore
+//!
+//! A backup is only swapped in after it passes validation:
+//! 1. The file has a SQLite header and passes `PRAGMA integrity_check`
+//! 2. The core tables exist and the schema version is not newer than this binary
+//! 3. A staged copy is migrated to the current schema
+//!
+//! The current database is saved as `<name>_pre_restore.db` before the swap.
+//! If the swap or reopen fails, that safety copy is put back automatically.
+
+use super::backup::{self, verify_integrity};
+use super::connection::{init_connection, DbState};
+use super::migrations::{get_schema_version, LATEST_VERSION};
+use crate::errors::AppError;
+use crate::models::BackupManifest;
+use rusqlite::{Connection, OpenFlags};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// First 16 bytes of every SQLite 3 database file
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// Tables a backup must contain to be accepted (the rest are created by migrations)
+pub const REQUIRED_TABLES: &[&str] = &["categories", "words", "settings"];
+
+/// Check that a file is a usable backup of this application's database
+///
+/// Returns the backup's manifest on success.
+pub fn validate_backup(source: &Path) -> Result<BackupManifest, AppError> {
+    if !source.exists() {
+        return Err(AppError::NotFoundError(
+            "Yedek dosyası bulunamadı".to_string(),
+        ));
+    }
+
+    let mut header = [0u8; 16];
+    let header_ok = File::open(source)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok()
+        && &header == SQLITE_HEADER;
+    if !header_ok {
+        return Err(AppError::ValidationError(
+            "Seçilen dosya bir SQLite veritabanı değil".to_string(),
+        ));
+    }
+
+    let conn = Connection::open_with_flags(source, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    verify_integrity(&conn)?;
+
+    for table in REQUIRED_TABLES {
+        let exists: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+            [table],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            return Err(AppError::ValidationError(format!(
+                "Yedek dosyası bu uygulamaya ait değil: '{}' tablosu eksik",
+                table
+            )));
+        }
+    }
+
+    let schema_version = get_schema_version(&conn)?;
+    if schema_version > LATEST_VERSION {
+        return Err(AppError::ValidationError(format!(
+            "Yedek daha yeni bir uygulama sürümüyle alınmış (şema {} > {})",
+            schema_version, LATEST_VERSION
+        )));
+    }
+    drop(conn);
+
+    backup::read_manifest(source)
+}
+
+/// Validate `source`, migrate a staged copy and swap it in for the current database
+///
+/// The shared connection is reopened on the restored file, no restart needed.
+/// Returns the manifest of the restored database.
+pub fn restore_from(state: &DbState, source: &Path) -> Result<BackupManifest, AppError> {
+    validate_backup(source)?;
+
+    let db_path = state.path()?;
+    let staging = sibling_path(&db_path, "restore_staging")?;
+    let safety = sibling_path(&db_path, "pre_restore")?;
+
+    // Bring a copy of the backup up to the current schema before touching live data
+    let staged = stage(source, &staging);
+    if staged.is_err() {
+        remove_database_files(&staging);
+    }
+    staged?;
+
+    // Safety copy of the current database (must succeed before anything is replaced)
+    {
+        let conn = state.conn()?;
+        backup::backup_to(&conn, &safety)?;
+    }
+
+    if let Err(restore_err) = state.swap_file(&staging) {
+        remove_database_files(&staging);
+
+        return match rollback(state, &db_path, &safety) {
+            Ok(()) => Err(AppError::DatabaseError(format!(
+                "Geri yükleme başarısız, önceki veriler geri getirildi: {}",
+                restore_err
+            ))),
+            Err(rollback_err) => Err(AppError::DatabaseError(format!(
+                "Geri yükleme başarısız: {}. Güvenlik kopyası ({}) geri yüklenemedi: {}",
+                restore_err,
+                safety.display(),
+                rollback_err
+            ))),
+        };
+    }
+
+    let conn = state.conn()?;
+    let mut manifest = backup::build_manifest(&conn)?;
+    manifest.created_at = conn.query_row("SELECT datetime('now')", [], |row| row.get(0))?;
+    manifest.size_bytes = fs::metadata(&db_path)?.len();
+
+    Ok(manifest)
+}
+
+/// Copy the backup to `staging` and migrate it to the current schema
+fn stage(source: &Path, staging: &Path) -> Result<(), AppError> {
+    // Leftovers of an interrupted restore must not leak into the new copy
+    remove_database_files(staging);
+    fs::copy(source, staging)?;
+
+    let conn = init_connection(staging)?;
+    verify_integrity(&conn)
+}
+
+/// Put the pre-restore safety copy back in place
+fn rollback(state: &DbState, db_path: &Path, safety: &Path) -> Result<(), AppError> {
+    let rollback_copy = sibling_path(db_path, "rollback")?;
+    fs::copy(safety, &rollback_copy)?;
+
+    state.swap_file(&rollback_copy)
+}
+
+fn remove_database_files(db_path: &Path) {
+    for file in super::database_files(db_path) {
+        let _ = fs::remove_file(&file);
+    }
+}
+
+/// `<dir>/<stem>_<suffix>.db` next to the database file
+fn sibling_path(db_path: &Path, suffix: &str) -> Result<PathBuf, AppError> {
+    let stem = db_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| AppError::FileSystemError("Geçersiz veritabanı yolu".to_string()))?;
+
+    Ok(db_path.with_file_name(format!("{}_{}.db", stem, suffix)))
+}
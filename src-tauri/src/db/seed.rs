@@ -3,8 +3,12 @@
 //! Handles initial data population on first launch:
 //! - Default category "Genel Kelimeler" (70 words)
 //! - Default application settings
+//! - Built-in scoring presets
+//! - Built-in game formats
 
-use rusqlite::{Connection, Result};
+use crate::models::Language;
+use crate::text;
+use rusqlite::{params, Connection, Result};
 
 /// Represents a word with its metadata
 struct Word {
@@ -388,10 +392,12 @@ fn is_database_seeded(conn: &Connection) -> Result<bool> {
 
 /// Insert the default category "Genel Kelimeler"
 /// Returns the category ID
+///
+/// The default words are English, so the category is English with the English alphabet.
 fn insert_default_category(conn: &Connection) -> Result<i64> {
     conn.execute(
-        "INSERT INTO categories (name, emoji, description, is_default)
-         VALUES (?1, ?2, ?3, 1)",
+        "INSERT INTO categories (name, emoji, description, is_default, language, alphabet)
+         VALUES (?1, ?2, ?3, 1, 'en', 'english')",
         [
             "Genel Kelimeler",
             "📦",
@@ -405,15 +411,16 @@ fn insert_default_category(conn: &Connection) -> Result<i64> {
 /// Insert all 70 default words for the given category
 fn insert_default_words(conn: &Connection, category_id: i64) -> Result<()> {
     let mut stmt = conn.prepare(
-        "INSERT INTO words (category_id, word, letter_count, hint)
-         VALUES (?1, ?2, ?3, ?4)",
+        "INSERT INTO words (category_id, word, normalized_word, letter_count, hint)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
     )?;
 
     for word in DEFAULT_WORDS {
-        stmt.execute([
-            &category_id.to_string(),
+        stmt.execute(params![
+            category_id,
             word.word,
-            &word.letter_count.to_string(),
+            text::collation_key(word.word, Language::En),
+            word.letter_count,
             word.hint,
         ])?;
     }
@@ -422,12 +429,15 @@ fn insert_default_words(conn: &Connection, category_id: i64) -> Result<()> {
 }
 
 /// Insert default application settings
-fn insert_default_settings(conn: &Connection) -> Result<()> {
+pub(crate) fn insert_default_settings(conn: &Connection) -> Result<()> {
     let settings = [
         ("sound_enabled", "true"),
         ("default_time", "300"),
         ("default_guesses", "3"),
         ("animation_speed", "normal"),
+        ("auto_backup_frequency", "daily"),
+        ("auto_backup_keep_daily", "7"),
+        ("auto_backup_keep_weekly", "4"),
     ];
 
     let mut stmt = conn.prepare("INSERT INTO settings (key, value) VALUES (?1, ?2)")?;
@@ -439,6 +449,62 @@ fn insert_default_settings(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+/// Insert the built-in scoring presets (skips presets that already exist)
+///
+/// Columns: name, points_per_letter, wrong_penalty_factor, timeout_penalty_factor,
+/// no_reveal_bonus, time_bonus_per_second
+pub(crate) fn insert_default_scoring_presets(conn: &Connection) -> Result<()> {
+    let presets: [(&str, i32, f64, f64, i32, i32); 3] = [
+        // Rules from docs/guncel-oyun-kurallari.md
+        ("Standart", 100, 1.0, 1.0, 0, 0),
+        // Younger students: wrong answers and timeouts cost nothing
+        ("Eksi Puan Yok", 100, 0.0, 0.0, 0, 0),
+        // Competitions: heavier penalties, rewards for bold and fast play
+        ("Yarışma", 100, 1.5, 1.5, 200, 2),
+    ];
+
+    let mut stmt = conn.prepare(
+        "INSERT OR IGNORE INTO scoring_presets
+         (name, points_per_letter, wrong_penalty_factor, timeout_penalty_factor,
+          no_reveal_bonus, time_bonus_per_second, is_default)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 1)",
+    )?;
+
+    for (name, points, wrong, timeout, no_reveal, time_bonus) in presets {
+        stmt.execute(params![name, points, wrong, timeout, no_reveal, time_bonus])?;
+    }
+
+    Ok(())
+}
+
+/// Insert the built-in game formats (skips formats that already exist)
+///
+/// Columns: name, min_length, max_length, words_per_length, total_words, word_order
+pub(crate) fn insert_default_game_formats(conn: &Connection) -> Result<()> {
+    let formats = [
+        // 4-10 letters, 2 per length (docs/guncel-oyun-kurallari.md)
+        ("Standart", 4, 10, 2, 14, "ascending"),
+        // Primary school: short words
+        ("İlkokul", 3, 7, 2, 10, "ascending"),
+        // Older students: long rounds
+        ("Uzun", 5, 12, 2, 16, "ascending"),
+    ];
+
+    let mut stmt = conn.prepare(
+        "INSERT OR IGNORE INTO game_formats
+         (name, min_length, max_length, words_per_length, total_words, word_order, is_default)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, 1)",
+    )?;
+
+    for (name, min_length, max_length, per_length, total, order) in formats {
+        stmt.execute(params![
+            name, min_length, max_length, per_length, total, order
+        ])?;
+    }
+
+    Ok(())
+}
+
 /// Run all seeding operations in a transaction
 ///
 /// This function is idempotent - it will only seed if the database is empty.
@@ -465,7 +531,9 @@ pub fn seed_database(conn: &Connection) -> Result<()> {
 }
 
 /// Internal seeding logic (executed within transaction)
-fn seed_database_internal(conn: &Connection) -> Result<()> {
+///
+/// Also used by `reset_all_data` after the tables have been emptied.
+pub(crate) fn seed_database_internal(conn: &Connection) -> Result<()> {
     // Insert default category
     let category_id = insert_default_category(conn)?;
 
@@ -475,6 +543,12 @@ fn seed_database_internal(conn: &Connection) -> Result<()> {
     // Insert default settings
     insert_default_settings(conn)?;
 
+    // Built-in scoring presets (also inserted by migration 3)
+    insert_default_scoring_presets(conn)?;
+
+    // Built-in game formats (also inserted by migration 4)
+    insert_default_game_formats(conn)?;
+
     // Verify word counts by letter
     verify_word_distribution(conn, category_id)?;
 
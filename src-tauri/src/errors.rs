@@ -33,6 +33,19 @@ impl std::fmt::Display for AppError {
 
 impl std::error::Error for AppError {}
 
+impl AppError {
+    /// Message without the error kind (for per-item reports)
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::DatabaseError(msg)
+            | AppError::ValidationError(msg)
+            | AppError::NotFoundError(msg)
+            | AppError::DuplicateError(msg)
+            | AppError::FileSystemError(msg) => msg,
+        }
+    }
+}
+
 /// Convert rusqlite errors to AppError
 impl From<rusqlite::Error> for AppError {
     fn from(err: rusqlite::Error) -> Self {
@@ -41,8 +54,11 @@ impl From<rusqlite::Error> for AppError {
                 AppError::NotFoundError("Kayıt bulunamadı".to_string())
             }
             rusqlite::Error::SqliteFailure(error, Some(msg)) => {
-                // Check for UNIQUE constraint violation
-                if error.code == rusqlite::ErrorCode::ConstraintViolation {
+                // A referenced record (e.g. category) no longer exists
+                if error.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_FOREIGNKEY {
+                    AppError::ValidationError(format!("Bağlı kayıt bulunamadı: {}", msg))
+                } else if error.code == rusqlite::ErrorCode::ConstraintViolation {
+                    // Check for UNIQUE constraint violation
                     AppError::DuplicateError(format!("Bu kayıt zaten mevcut: {}", msg))
                 } else {
                     AppError::DatabaseError(format!("Veritabanı hatası: {}", msg))
@@ -0,0 +1,406 @@
+//! Export file format
+//!
+//! Category and library exports are written as an [`ExportFile`]: the
+//! payload wrapped with its format version, the app version, export date,
+//! author, language, word count and a SHA-256 checksum of the payload.
+//!
+//! Reading a file ([`open`]) upgrades older versions step by step to
+//! [`FORMAT_VERSION`], then checks the checksum and word count. Versions:
+//! - 1: a bare `CategoryExportData`, or the library bundle
+//!   `{format_version: 1, exported_at, categories}`
+//! - 2: [`ExportFile`]
+//!
+//! Files are written straight to disk ([`write_json`], [`write_csv`]) through
+//! a temporary file, so a failed export never leaves a half-written file.
+
+use crate::errors::AppError;
+use crate::models::{CategoryExportData, ExportFile, ExportPayload};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// Layout version written by this app
+///
+/// Bump when the layout changes and add an upgrade from the previous
+/// version to [`UPGRADES`].
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Upgrade from a version to the next one, operating on the raw JSON object
+type Upgrade = fn(Map<String, Value>) -> Result<Map<String, Value>, AppError>;
+
+/// `UPGRADES[n - 1]` upgrades a version `n` file to version `n + 1`
+const UPGRADES: [Upgrade; FORMAT_VERSION as usize - 1] = [upgrade_v1];
+
+/// Wrap a payload for export
+pub fn seal(
+    payload: ExportPayload,
+    exported_at: Option<String>,
+    author: Option<String>,
+) -> Result<ExportFile, AppError> {
+    let sha256 = payload_checksum(&serde_json::to_value(&payload)?);
+    let categories = payload_categories(&payload);
+    let word_count: usize = categories.iter().map(|category| category.words.len()).sum();
+
+    let mut languages = categories.iter().map(|category| category.category.language);
+    let first = languages.next().flatten();
+    let language = first.filter(|_| languages.all(|language| language == first));
+
+    Ok(ExportFile {
+        format_version: FORMAT_VERSION,
+        app_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        exported_at,
+        author: author
+            .map(|author| author.trim().to_string())
+            .filter(|author| !author.is_empty()),
+        language,
+        word_count: i32::try_from(word_count).unwrap_or(i32::MAX),
+        sha256,
+        payload,
+    })
+}
+
+/// Read an export file of any supported version
+///
+/// Fails with a `ValidationError` naming the problem when the file is not an
+/// export, comes from a newer app, does not match the layout of its version,
+/// or its checksum or word count does not match the payload.
+pub fn open(value: Value) -> Result<ExportFile, AppError> {
+    let Value::Object(mut file) = value else {
+        return Err(invalid("dosya bir JSON nesnesi değil"));
+    };
+
+    let version = file_version(&file)?;
+    if version > FORMAT_VERSION {
+        let written_by = file
+            .get("app_version")
+            .and_then(Value::as_str)
+            .map(|app_version| format!(" (Bil Bakalım {} ile oluşturulmuş)", app_version))
+            .unwrap_or_default();
+
+        return Err(AppError::ValidationError(format!(
+            "Dosya biçimi sürüm {}{}, bu uygulama en fazla sürüm {} okuyabilir. \
+             Dosyayı açmak için uygulamayı güncelleyin",
+            version, written_by, FORMAT_VERSION
+        )));
+    }
+
+    for upgrade in &UPGRADES[version as usize - 1..] {
+        file = upgrade(file)?;
+    }
+
+    let checksum = file
+        .get("payload")
+        .map(payload_checksum)
+        .ok_or_else(|| invalid("\"payload\" alanı yok"))?;
+
+    let file: ExportFile =
+        serde_json::from_value(Value::Object(file)).map_err(|e| invalid(&e.to_string()))?;
+
+    if !file.sha256.eq_ignore_ascii_case(&checksum) {
+        return Err(AppError::ValidationError(
+            "Dosya bozuk veya sonradan değiştirilmiş: SHA-256 özeti içerikle uyuşmuyor".to_string(),
+        ));
+    }
+
+    let word_count: usize = payload_categories(&file.payload)
+        .iter()
+        .map(|category| category.words.len())
+        .sum();
+    if usize::try_from(file.word_count).ok() != Some(word_count) {
+        return Err(AppError::ValidationError(format!(
+            "Dosyadaki kelime sayısı ({}) içerikteki kelime sayısıyla ({}) uyuşmuyor",
+            file.word_count, word_count
+        )));
+    }
+
+    Ok(file)
+}
+
+/// Read an export file from disk, see [`open`]
+pub fn read_file(path: &Path) -> Result<ExportFile, AppError> {
+    let reader = BufReader::new(File::open(path)?);
+    let value = serde_json::from_reader(reader).map_err(|e| invalid(&e.to_string()))?;
+
+    open(value)
+}
+
+/// Write an export file as pretty-printed JSON
+pub fn write_json(file: &ExportFile, path: &Path) -> Result<(), AppError> {
+    write_atomic(path, |writer| {
+        serde_json::to_writer_pretty(&mut *writer, file)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    })
+}
+
+/// Write the words of a category as CSV
+///
+/// Uses `;` and a UTF-8 BOM so Excel opens the file with Turkish letters
+/// intact; `import_words` reads it back.
+pub fn write_csv(category: &CategoryExportData, path: &Path) -> Result<(), AppError> {
+    write_atomic(path, |writer| {
+        writer.write_all(b"\xEF\xBB\xBF")?;
+
+        let mut csv = csv::WriterBuilder::new()
+            .delimiter(b';')
+            .from_writer(writer);
+        let write_error =
+            |e: csv::Error| AppError::FileSystemError(format!("CSV yazılamadı: {}", e));
+
+        csv.write_record(["Kelime", "İpucu"]).map_err(write_error)?;
+        for word in &category.words {
+            csv.write_record([word.word.as_str(), word.hint.as_str()])
+                .map_err(write_error)?;
+        }
+        csv.flush()?;
+        Ok(())
+    })
+}
+
+/// Write `path` through a temporary file next to it
+pub(crate) fn write_atomic(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<(), AppError>,
+) -> Result<(), AppError> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let result = File::create(&temp_path)
+        .map_err(AppError::from)
+        .and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            write(&mut writer)?;
+            writer
+                .into_inner()
+                .map_err(std::io::IntoInnerError::into_error)?
+                .sync_all()?;
+            Ok(())
+        })
+        .and_then(|()| fs::rename(&temp_path, path).map_err(AppError::from));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+/// SHA-256 of a payload, hex encoded
+///
+/// The payload is hashed as compact JSON with object keys sorted, so the
+/// checksum does not depend on key order or formatting of the file.
+pub fn payload_checksum(payload: &Value) -> String {
+    let mut json = String::new();
+    write_canonical(payload, &mut json);
+
+    Sha256::digest(json.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+impl ExportPayload {
+    /// The category of a category export
+    pub fn into_category(self) -> Result<CategoryExportData, AppError> {
+        match self {
+            Self::Category(category) => Ok(category),
+            Self::Library { .. } => Err(AppError::ValidationError(
+                "Bu dosya bir kütüphane paketi, kategori olarak içe aktarılamaz. \
+                 Kütüphane içe aktarmayı kullanın"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Every category of the export (a category export holds one)
+    pub fn into_categories(self) -> Vec<CategoryExportData> {
+        match self {
+            Self::Category(category) => vec![category],
+            Self::Library { categories } => categories,
+        }
+    }
+}
+
+fn payload_categories(payload: &ExportPayload) -> &[CategoryExportData] {
+    match payload {
+        ExportPayload::Category(category) => std::slice::from_ref(category),
+        ExportPayload::Library { categories } => categories,
+    }
+}
+
+/// Format version of a raw file
+///
+/// Files without `format_version` are version 1 category exports.
+fn file_version(file: &Map<String, Value>) -> Result<u32, AppError> {
+    match file.get("format_version") {
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .filter(|&version| version > 0)
+            .ok_or_else(|| invalid(&format!("format_version geçersiz: {}", version))),
+        None if file.contains_key("category") && file.contains_key("words") => Ok(1),
+        None => Err(invalid(
+            "format_version alanı yok, dosya bir dışa aktarma dosyası değil",
+        )),
+    }
+}
+
+/// Version 1 to 2: wrap the category export or library bundle
+fn upgrade_v1(mut file: Map<String, Value>) -> Result<Map<String, Value>, AppError> {
+    file.remove("format_version");
+    let exported_at = file
+        .remove("exported_at")
+        .and_then(|exported_at| exported_at.as_str().map(str::to_string));
+
+    let payload = if let Some(categories) = file.remove("categories") {
+        let categories = serde_json::from_value(categories).map_err(|e| invalid(&e.to_string()))?;
+        ExportPayload::Library { categories }
+    } else {
+        let category =
+            serde_json::from_value(Value::Object(file)).map_err(|e| invalid(&e.to_string()))?;
+        ExportPayload::Category(category)
+    };
+
+    let mut sealed = seal(payload, exported_at, None)?;
+    sealed.format_version = 2;
+    sealed.app_version = None;
+
+    let Value::Object(file) = serde_json::to_value(sealed)? else {
+        unreachable!("ExportFile serializes to an object");
+    };
+    Ok(file)
+}
+
+fn invalid(reason: &str) -> AppError {
+    AppError::ValidationError(format!("Dosya biçimi geçersiz: {}", reason))
+}
+
+/// Compact JSON with object keys sorted
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+
+            out.push('{');
+            for (index, key) in keys.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Language;
+    use serde_json::json;
+
+    fn category_v1() -> Value {
+        json!({
+            "category": {"name": "Meyveler", "emoji": "🍎", "description": null, "language": "tr"},
+            "words": [
+                {"word": "ELMA", "letter_count": 4, "hint": "Kırmızı"},
+                {"word": "ARMUT", "letter_count": 5, "hint": ""}
+            ]
+        })
+    }
+
+    #[test]
+    fn sealed_files_open_unchanged() {
+        let category = serde_json::from_value(category_v1()).unwrap();
+        let file = seal(
+            ExportPayload::Category(category),
+            Some("2026-01-01 10:00:00".to_string()),
+            Some("  Ayşe Öğretmen ".to_string()),
+        )
+        .unwrap();
+        assert_eq!(file.word_count, 2);
+        assert_eq!(file.language, Some(Language::Tr));
+        assert_eq!(file.author.as_deref(), Some("Ayşe Öğretmen"));
+
+        let opened = open(serde_json::to_value(&file).unwrap()).unwrap();
+        assert_eq!(opened.sha256, file.sha256);
+        assert_eq!(opened.payload.into_category().unwrap().words.len(), 2);
+    }
+
+    #[test]
+    fn checksum_ignores_key_order_but_not_content() {
+        let a = json!({"b": 1, "a": [{"y": "İ", "x": null}]});
+        let b = json!({"a": [{"x": null, "y": "İ"}], "b": 1});
+        assert_eq!(payload_checksum(&a), payload_checksum(&b));
+        assert_ne!(payload_checksum(&a), payload_checksum(&json!({"b": 2})));
+    }
+
+    #[test]
+    fn version_1_files_are_upgraded() {
+        let file = open(category_v1()).unwrap();
+        assert_eq!(file.format_version, FORMAT_VERSION);
+        assert_eq!(file.app_version, None);
+        assert_eq!(file.word_count, 2);
+
+        let bundle = json!({
+            "format_version": 1,
+            "exported_at": "2025-06-01 09:00:00",
+            "categories": [category_v1(), category_v1()]
+        });
+        let file = open(bundle).unwrap();
+        assert_eq!(file.exported_at.as_deref(), Some("2025-06-01 09:00:00"));
+        assert_eq!(file.word_count, 4);
+        assert!(file.payload.into_category().is_err());
+    }
+
+    #[test]
+    fn bad_files_are_rejected_with_the_reason() {
+        let category = serde_json::from_value(category_v1()).unwrap();
+        let sealed = seal(ExportPayload::Category(category), None, None).unwrap();
+        let sealed = serde_json::to_value(sealed).unwrap();
+
+        let mut future = sealed.clone();
+        future["format_version"] = json!(FORMAT_VERSION + 1);
+        future["app_version"] = json!("9.0.0");
+        let error = open(future).unwrap_err().to_string();
+        assert!(error.contains("sürüm 3 (Bil Bakalım 9.0.0"), "{}", error);
+
+        let mut tampered = sealed.clone();
+        tampered["payload"]["words"][0]["hint"] = json!("Yeşil");
+        assert!(open(tampered).unwrap_err().to_string().contains("SHA-256"));
+
+        let mut miscounted = sealed;
+        miscounted["word_count"] = json!(3);
+        assert!(open(miscounted).unwrap_err().to_string().contains("(3)"));
+
+        assert!(open(json!({"format_version": "2"})).is_err());
+        assert!(open(json!({"words": []})).is_err());
+    }
+}
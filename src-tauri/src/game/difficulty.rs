@@ -0,0 +1,188 @@
+//! Word difficulty from play history
+//!
+//! A word is hard when players miss it, need many letters to get it or run
+//! out of time on it. The three rates are combined into a score between 0
+//! (easiest) and 100 (hardest). Scores of rarely played words are pulled
+//! towards the middle, so a single unlucky game does not mark a word as hard.
+//! Word selection aims at a [`Band`] of scores.
+
+/// Results of one word summed over all games it was played in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlayCounts {
+    pub played: i32,
+    pub found: i32,
+    pub timeout: i32,
+    /// Letters revealed, summed over all plays
+    pub letters_revealed: i32,
+}
+
+/// Share of the score from words not being found (wrong, skipped or timed out)
+const MISS_WEIGHT: f64 = 0.6;
+/// Share of the score from the letters revealed relative to the word length
+const REVEAL_WEIGHT: f64 = 0.3;
+/// Share of the score from running out of time
+const TIMEOUT_WEIGHT: f64 = 0.1;
+
+/// Number of imaginary plays at medium difficulty mixed into every score
+const PRIOR_PLAYS: f64 = 3.0;
+const MEDIUM: f64 = 0.5;
+
+/// Score assumed for words that were never played
+pub const UNPLAYED_SCORE: f64 = MEDIUM * 100.0;
+
+/// Upper limit of the easy band and lower limit of the hard band
+const EASY_MAX: f64 = 40.0;
+const HARD_MIN: f64 = 60.0;
+
+/// Range of difficulty scores aimed at by word selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Band {
+    /// Below 40
+    Easy,
+    /// 40 to 60
+    Medium,
+    /// Above 60
+    Hard,
+}
+
+impl Band {
+    /// How far `score` is outside the band, 0 inside it
+    pub fn distance(self, score: f64) -> f64 {
+        let (min, max) = match self {
+            Self::Easy => (0.0, EASY_MAX),
+            Self::Medium => (EASY_MAX, HARD_MIN),
+            Self::Hard => (HARD_MIN, 100.0),
+        };
+        (min - score).max(score - max).max(0.0)
+    }
+
+    /// Band of the `slot`-th of `slots` letter lengths in a game that ramps
+    /// from easy to hard: first third easy, last third hard
+    pub fn ramp(slot: usize, slots: usize) -> Self {
+        let third = slot * 3 / slots.max(1);
+        match third {
+            0 => Self::Easy,
+            1 => Self::Medium,
+            _ => Self::Hard,
+        }
+    }
+}
+
+impl PlayCounts {
+    /// Share of plays in which the word was found
+    pub fn found_rate(&self) -> Option<f64> {
+        self.rate(self.found)
+    }
+
+    /// Share of plays that ended with the time running out
+    pub fn timeout_rate(&self) -> Option<f64> {
+        self.rate(self.timeout)
+    }
+
+    /// Letters revealed per play
+    pub fn avg_letters_revealed(&self) -> Option<f64> {
+        self.rate(self.letters_revealed)
+    }
+
+    /// Difficulty score of a word with `letter_count` letters, `None` if never played
+    pub fn difficulty(&self, letter_count: i32) -> Option<f64> {
+        let found_rate = self.found_rate()?;
+        let timeout_rate = self.timeout_rate()?;
+        let reveal_ratio =
+            (self.avg_letters_revealed()? / f64::from(letter_count.max(1))).clamp(0.0, 1.0);
+
+        let raw = MISS_WEIGHT * (1.0 - found_rate)
+            + REVEAL_WEIGHT * reveal_ratio
+            + TIMEOUT_WEIGHT * timeout_rate;
+        let played = f64::from(self.played);
+        let smoothed = (raw * played + MEDIUM * PRIOR_PLAYS) / (played + PRIOR_PLAYS);
+
+        // One decimal on the 0-100 scale
+        Some((smoothed * 1000.0).round() / 10.0)
+    }
+
+    fn rate(&self, count: i32) -> Option<f64> {
+        (self.played > 0).then(|| round(f64::from(count) / f64::from(self.played)))
+    }
+}
+
+/// Round to three decimals for display
+fn round(value: f64) -> f64 {
+    (value * 1000.0).round() / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(played: i32, found: i32, timeout: i32, letters_revealed: i32) -> PlayCounts {
+        PlayCounts {
+            played,
+            found,
+            timeout,
+            letters_revealed,
+        }
+    }
+
+    #[test]
+    fn unplayed_words_have_no_score() {
+        let counts = PlayCounts::default();
+
+        assert_eq!(counts.found_rate(), None);
+        assert_eq!(counts.difficulty(5), None);
+    }
+
+    #[test]
+    fn missed_words_score_higher_than_found_ones() {
+        let easy = counts(20, 20, 0, 0).difficulty(5).unwrap();
+        let hard = counts(20, 0, 20, 100).difficulty(5).unwrap();
+
+        assert!(easy < 10.0, "{}", easy);
+        assert!(hard > 90.0, "{}", hard);
+    }
+
+    #[test]
+    fn revealed_letters_are_relative_to_word_length() {
+        let short = counts(10, 10, 0, 20).difficulty(4).unwrap();
+        let long = counts(10, 10, 0, 20).difficulty(10).unwrap();
+
+        assert!(short > long);
+        assert_eq!(counts(10, 10, 0, 20).avg_letters_revealed(), Some(2.0));
+    }
+
+    #[test]
+    fn bands_measure_the_distance_to_their_range() {
+        assert_eq!(Band::Easy.distance(25.0), 0.0);
+        assert_eq!(Band::Easy.distance(55.0), 15.0);
+        assert_eq!(Band::Medium.distance(UNPLAYED_SCORE), 0.0);
+        assert_eq!(Band::Hard.distance(40.0), 20.0);
+    }
+
+    #[test]
+    fn ramps_go_from_easy_to_hard() {
+        let bands: Vec<Band> = (0..7).map(|slot| Band::ramp(slot, 7)).collect();
+
+        assert_eq!(
+            bands,
+            [
+                Band::Easy,
+                Band::Easy,
+                Band::Easy,
+                Band::Medium,
+                Band::Medium,
+                Band::Hard,
+                Band::Hard
+            ]
+        );
+        assert_eq!(Band::ramp(0, 1), Band::Easy);
+    }
+
+    #[test]
+    fn few_plays_stay_near_medium() {
+        let once = counts(1, 0, 1, 5).difficulty(5).unwrap();
+        let often = counts(30, 0, 30, 150).difficulty(5).unwrap();
+
+        assert!(once < often);
+        assert!((50.0..80.0).contains(&once), "{}", once);
+    }
+}
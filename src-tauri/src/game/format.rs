@@ -0,0 +1,142 @@
+//! Game formats (word-length ladders)
+//!
+//! A [`GameFormat`] decides which word lengths are played and how many words
+//! of each length a participant gets. Word selection and category validation
+//! both work from the per-length quotas computed here.
+
+use crate::errors::AppError;
+use crate::models::GameFormat;
+
+/// Shortest word accepted anywhere in the application
+pub const MIN_WORD_LENGTH: i32 = 2;
+
+/// Longest word accepted anywhere in the application
+pub const MAX_WORD_LENGTH: i32 = 15;
+
+/// Check that a word length is within the application-wide limits
+pub fn validate_word_length(letter_count: i32) -> Result<(), AppError> {
+    if (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&letter_count) {
+        return Ok(());
+    }
+
+    Err(AppError::ValidationError(format!(
+        "Kelime uzunluğu {}-{} harf arasında olmalıdır",
+        MIN_WORD_LENGTH, MAX_WORD_LENGTH
+    )))
+}
+
+/// Check that a format is usable
+pub fn validate_format(format: &GameFormat) -> Result<(), AppError> {
+    if format.min_length < MIN_WORD_LENGTH
+        || format.max_length > MAX_WORD_LENGTH
+        || format.min_length > format.max_length
+    {
+        return Err(AppError::ValidationError(format!(
+            "Harf aralığı {}-{} içinde olmalı ve en kısa uzunluk en uzundan büyük olmamalı",
+            MIN_WORD_LENGTH, MAX_WORD_LENGTH
+        )));
+    }
+
+    if format.words_per_length < 1 {
+        return Err(AppError::ValidationError(
+            "Her uzunluktan en az 1 kelime olmalı".to_string(),
+        ));
+    }
+
+    let lengths = length_count(format);
+    let max_total = lengths * format.words_per_length;
+    if format.total_words < lengths || format.total_words > max_total {
+        return Err(AppError::ValidationError(format!(
+            "Toplam kelime sayısı {} ile {} arasında olmalı",
+            lengths, max_total
+        )));
+    }
+
+    Ok(())
+}
+
+/// Words of each length a participant gets, as `(letter_count, count)` pairs
+///
+/// `total_words` is spread evenly over the lengths; when it does not divide
+/// evenly the shorter lengths get one more word. The standard format gives
+/// 2 words for every length from 4 to 10.
+pub fn length_quotas(format: &GameFormat) -> Vec<(i32, i32)> {
+    let lengths = length_count(format);
+    let base = format.total_words / lengths;
+    let extra = format.total_words % lengths;
+
+    (format.min_length..=format.max_length)
+        .enumerate()
+        .map(|(index, letter_count)| {
+            let bonus = i32::from(i32::try_from(index).unwrap_or(i32::MAX) < extra);
+            (letter_count, base + bonus)
+        })
+        .collect()
+}
+
+/// How many participants a category supports, given its word counts per length
+///
+/// `count_for` returns the number of words of a length in the category.
+pub fn max_participants(format: &GameFormat, count_for: impl Fn(i32) -> i32) -> i32 {
+    length_quotas(format)
+        .into_iter()
+        .map(|(letter_count, quota)| count_for(letter_count) / quota)
+        .min()
+        .unwrap_or(0)
+}
+
+fn length_count(format: &GameFormat) -> i32 {
+    format.max_length - format.min_length + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::WordOrder;
+
+    fn format(
+        min_length: i32,
+        max_length: i32,
+        words_per_length: i32,
+        total_words: i32,
+    ) -> GameFormat {
+        GameFormat {
+            min_length,
+            max_length,
+            words_per_length,
+            total_words,
+            order: WordOrder::Ascending,
+        }
+    }
+
+    #[test]
+    fn standard_format_is_two_words_per_length() {
+        let quotas = length_quotas(&GameFormat::default());
+
+        assert_eq!(quotas.len(), 7);
+        assert!(quotas.iter().all(|&(_, count)| count == 2));
+        assert_eq!(quotas.first(), Some(&(4, 2)));
+        assert_eq!(quotas.last(), Some(&(10, 2)));
+    }
+
+    #[test]
+    fn uneven_totals_favour_shorter_lengths() {
+        let quotas = length_quotas(&format(3, 7, 2, 8));
+
+        assert_eq!(quotas, vec![(3, 2), (4, 2), (5, 2), (6, 1), (7, 1)]);
+        assert_eq!(
+            max_participants(&format(3, 7, 2, 8), |length| length * 2),
+            3
+        );
+    }
+
+    #[test]
+    fn invalid_formats_are_rejected() {
+        assert!(validate_format(&GameFormat::default()).is_ok());
+        assert!(validate_format(&format(5, 12, 2, 16)).is_ok());
+        assert!(validate_format(&format(8, 4, 2, 10)).is_err());
+        assert!(validate_format(&format(1, 4, 2, 6)).is_err());
+        assert!(validate_format(&format(4, 10, 2, 15)).is_err());
+        assert!(validate_format(&format(4, 10, 2, 6)).is_err());
+    }
+}
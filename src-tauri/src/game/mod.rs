@@ -0,0 +1,118 @@
+//! Game engine
+//!
+//! The backend owns the running game: scoring and timers are applied here and
+//! the results written to `game_history` are computed from this state, not
+//! taken from the frontend.
+//!
+//! Modules:
+//! - `session`: the game session state machine
+//! - `scoring`: word values and participant ranking
+//! - `format`: word-length ladders used for word selection and validation
+//! - `difficulty`: word difficulty scores from play history
+//! - `selection`: random choice among the words of a length
+//! - `word_set`: checks of curated word sets against a format
+
+pub mod difficulty;
+pub mod format;
+pub mod scoring;
+pub mod selection;
+pub mod session;
+pub mod word_set;
+
+pub use session::{
+    GameMode, GamePhase, GameSession, ParticipantType, SessionConfig, SessionParticipant,
+    SessionWord, WordOutcome,
+};
+
+use crate::errors::AppError;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Managed state holding the active game session (at most one)
+#[derive(Default)]
+pub struct GameSessionState {
+    active: Mutex<Option<ActiveGame>>,
+}
+
+struct ActiveGame {
+    session: GameSession,
+    /// Point up to which the session timers have been advanced
+    synced_at: Instant,
+}
+
+impl GameSessionState {
+    /// Replace any active session with `session`
+    pub fn start(&self, session: GameSession) -> Result<GameSession, AppError> {
+        let mut active = self.lock()?;
+        *active = Some(ActiveGame {
+            session: session.clone(),
+            synced_at: Instant::now(),
+        });
+
+        Ok(session)
+    }
+
+    /// Snapshot of the active session with its timers brought up to date
+    pub fn current(&self) -> Result<Option<GameSession>, AppError> {
+        let mut active = self.lock()?;
+
+        Ok(active.as_mut().map(|game| {
+            game.sync();
+            game.session.clone()
+        }))
+    }
+
+    /// Apply `action` to the active session after bringing its timers up to date
+    ///
+    /// Returns the updated session.
+    pub fn update<F>(&self, action: F) -> Result<GameSession, AppError>
+    where
+        F: FnOnce(&mut GameSession) -> Result<(), AppError>,
+    {
+        let mut active = self.lock()?;
+        let game = active
+            .as_mut()
+            .ok_or_else(|| AppError::NotFoundError("Aktif oyun yok".to_string()))?;
+
+        game.sync();
+        let was_running = game.session.is_clock_running();
+        action(&mut game.session)?;
+        if !was_running {
+            // Stopped timers restart from the moment of the action
+            game.synced_at = Instant::now();
+        }
+
+        Ok(game.session.clone())
+    }
+
+    /// Remove and return the active session
+    pub fn take(&self) -> Result<Option<GameSession>, AppError> {
+        Ok(self.lock()?.take().map(|mut game| {
+            game.sync();
+            game.session
+        }))
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Option<ActiveGame>>, AppError> {
+        self.active
+            .lock()
+            .map_err(|_| AppError::DatabaseError("Oyun durumu kilitlenemedi".to_string()))
+    }
+}
+
+impl ActiveGame {
+    /// Advance the session by the whole seconds elapsed since the last sync
+    fn sync(&mut self) {
+        let elapsed = self.synced_at.elapsed().as_secs();
+        if elapsed == 0 {
+            return;
+        }
+
+        if self.session.is_clock_running() {
+            self.session
+                .tick(u32::try_from(elapsed).unwrap_or(u32::MAX));
+        }
+        // Keep the fraction of the current second
+        self.synced_at += Duration::from_secs(elapsed);
+    }
+}
@@ -0,0 +1,179 @@
+//! Scoring and ranking rules
+//!
+//! See `docs/guncel-oyun-kurallari.md`, section 4 (Puan Hesaplama Algoritması).
+//! The numbers come from a [`ScoringRules`] preset; the standard preset gives
+//! 100 points per letter and subtracts the full value on wrong answers.
+
+use super::session::{SessionParticipant, WordOutcome};
+use crate::errors::AppError;
+use crate::models::ScoringRules;
+use std::cmp::Ordering;
+
+/// Largest accepted penalty factor
+const MAX_PENALTY_FACTOR: f64 = 5.0;
+
+/// Largest accepted points per letter
+const MAX_POINTS_PER_LETTER: i32 = 10_000;
+
+/// Largest accepted no-reveal bonus and time bonus per second
+const MAX_BONUS: i32 = 10_000;
+
+/// Check that scoring rules are usable
+pub fn validate_rules(rules: &ScoringRules) -> Result<(), AppError> {
+    if !(1..=MAX_POINTS_PER_LETTER).contains(&rules.points_per_letter) {
+        return Err(AppError::ValidationError(format!(
+            "Harf puanı 1 ile {} arasında olmalı",
+            MAX_POINTS_PER_LETTER
+        )));
+    }
+
+    for factor in [rules.wrong_penalty_factor, rules.timeout_penalty_factor] {
+        if !(0.0..=MAX_PENALTY_FACTOR).contains(&factor) {
+            return Err(AppError::ValidationError(format!(
+                "Ceza katsayısı 0 ile {} arasında olmalı",
+                MAX_PENALTY_FACTOR
+            )));
+        }
+    }
+
+    for bonus in [rules.no_reveal_bonus, rules.time_bonus_per_second] {
+        if !(0..=MAX_BONUS).contains(&bonus) {
+            return Err(AppError::ValidationError(format!(
+                "Bonus puanlar 0 ile {} arasında olmalı",
+                MAX_BONUS
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Current value of a word: `(letters - revealed) × points per letter`
+pub fn word_value(rules: &ScoringRules, letter_count: i32, letters_revealed: i32) -> i32 {
+    letter_count
+        .saturating_sub(letters_revealed)
+        .max(0)
+        .saturating_mul(rules.points_per_letter)
+}
+
+/// Points earned (or lost) when a word is resolved with `outcome`
+pub fn outcome_points(
+    rules: &ScoringRules,
+    outcome: WordOutcome,
+    value: i32,
+    letters_revealed: i32,
+) -> i32 {
+    match outcome {
+        WordOutcome::Found if letters_revealed == 0 => value.saturating_add(rules.no_reveal_bonus),
+        WordOutcome::Found => value,
+        WordOutcome::Wrong => -penalty(value, rules.wrong_penalty_factor),
+        WordOutcome::Timeout => -penalty(value, rules.timeout_penalty_factor),
+        WordOutcome::Skipped | WordOutcome::Unplayed => 0,
+    }
+}
+
+/// Bonus for finishing all words with `remaining_seconds` left
+pub fn time_bonus(rules: &ScoringRules, remaining_seconds: u32) -> i32 {
+    i32::try_from(remaining_seconds)
+        .unwrap_or(i32::MAX)
+        .saturating_mul(rules.time_bonus_per_second)
+}
+
+fn penalty(value: i32, factor: f64) -> i32 {
+    // Float to int casts saturate, so huge values stay at i32::MAX
+    (f64::from(value) * factor).round() as i32
+}
+
+/// Rank participants (1 = winner), returned in the participants' order
+///
+/// Higher score wins; ties are broken by fewer revealed letters, then by less
+/// time used. Participants equal on all three share the same rank.
+pub fn rank_participants(participants: &[SessionParticipant]) -> Vec<i32> {
+    let mut order: Vec<usize> = (0..participants.len()).collect();
+    order.sort_by(|&a, &b| compare(&participants[a], &participants[b]));
+
+    let mut ranks = vec![0; participants.len()];
+    for (position, &index) in order.iter().enumerate() {
+        ranks[index] = match position.checked_sub(1).map(|prev| order[prev]) {
+            Some(prev) if compare(&participants[prev], &participants[index]).is_eq() => ranks[prev],
+            _ => i32::try_from(position + 1).unwrap_or(i32::MAX),
+        };
+    }
+
+    ranks
+}
+
+fn compare(a: &SessionParticipant, b: &SessionParticipant) -> Ordering {
+    b.score
+        .cmp(&a.score)
+        .then(a.letters_revealed.cmp(&b.letters_revealed))
+        .then(a.elapsed_time_seconds.cmp(&b.elapsed_time_seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> ScoringRules {
+        ScoringRules {
+            points_per_letter: 100,
+            wrong_penalty_factor: 1.0,
+            timeout_penalty_factor: 1.0,
+            no_reveal_bonus: 0,
+            time_bonus_per_second: 0,
+        }
+    }
+
+    #[test]
+    fn rules_out_of_range_are_rejected() {
+        assert!(validate_rules(&rules()).is_ok());
+
+        for invalid in [
+            ScoringRules {
+                points_per_letter: 0,
+                ..rules()
+            },
+            ScoringRules {
+                points_per_letter: MAX_POINTS_PER_LETTER + 1,
+                ..rules()
+            },
+            ScoringRules {
+                wrong_penalty_factor: 5.5,
+                ..rules()
+            },
+            ScoringRules {
+                no_reveal_bonus: -1,
+                ..rules()
+            },
+            ScoringRules {
+                time_bonus_per_second: i32::MAX,
+                ..rules()
+            },
+        ] {
+            assert!(validate_rules(&invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn points_saturate_instead_of_overflowing() {
+        let rules = ScoringRules {
+            points_per_letter: i32::MAX,
+            wrong_penalty_factor: MAX_PENALTY_FACTOR,
+            no_reveal_bonus: i32::MAX,
+            time_bonus_per_second: i32::MAX,
+            ..rules()
+        };
+
+        let value = word_value(&rules, 10, 0);
+        assert_eq!(value, i32::MAX);
+        assert_eq!(
+            outcome_points(&rules, WordOutcome::Found, value, 0),
+            i32::MAX
+        );
+        assert_eq!(
+            outcome_points(&rules, WordOutcome::Wrong, value, 0),
+            -i32::MAX
+        );
+        assert_eq!(time_bonus(&rules, u32::MAX), i32::MAX);
+    }
+}
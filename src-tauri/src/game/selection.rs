@@ -0,0 +1,374 @@
+//! Picking words of one letter length
+//!
+//! The database side collects the candidate words of a length together with
+//! how often they were played recently and how difficult they are; the
+//! choice between them is made here so it can be tested without a database.
+//!
+//! A difficulty band is preferred over avoiding recent words: when the band
+//! has too few words, all of them are used, recent or not.
+
+use super::difficulty::{Band, UNPLAYED_SCORE};
+use crate::errors::AppError;
+use crate::models::{RecencyStrategy, Word};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Largest seed, so seeds stay exact as JavaScript numbers (2^53 - 1)
+pub const MAX_SEED: u64 = (1 << 53) - 1;
+
+/// Random number generator of word draws
+///
+/// `ChaCha8` gives the same sequence for a seed on every platform and release,
+/// unlike `StdRng`.
+pub type DrawRng = ChaCha8Rng;
+
+/// Seed for a new draw
+pub fn new_seed() -> u64 {
+    rand::thread_rng().gen_range(0..=MAX_SEED)
+}
+
+/// Generator for the draw with `seed`
+pub fn seeded(seed: u64) -> Result<DrawRng, AppError> {
+    if seed > MAX_SEED {
+        return Err(AppError::ValidationError(format!(
+            "Tohum 0 ile {} arasında olmalı",
+            MAX_SEED
+        )));
+    }
+
+    Ok(DrawRng::seed_from_u64(seed))
+}
+
+/// Weight of a word per recent play under [`RecencyStrategy::Downweight`]
+///
+/// A word played once is a quarter as likely as an unplayed one, twice a
+/// sixteenth, and so on.
+const DOWNWEIGHT_FACTOR: f64 = 0.25;
+
+/// Word that may be selected
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub word: Word,
+    /// Times the word was played in the recent window
+    pub recent_plays: u32,
+    /// Most recent game it was played in, 0 being the latest game
+    pub last_played: Option<usize>,
+    /// Difficulty score (see `super::difficulty`)
+    pub difficulty: f64,
+}
+
+impl Candidate {
+    /// Candidate that was not played recently and has no play history
+    pub const fn fresh(word: Word) -> Self {
+        Self {
+            word,
+            recent_plays: 0,
+            last_played: None,
+            difficulty: UNPLAYED_SCORE,
+        }
+    }
+
+    const fn is_recent(&self) -> bool {
+        self.recent_plays > 0
+    }
+}
+
+/// Words picked for a length
+#[derive(Debug, Clone)]
+pub struct Pick {
+    pub words: Vec<Word>,
+    /// Recently played words taken under [`RecencyStrategy::Exclude`]
+    /// because there were not enough other words
+    pub recent_fallback: usize,
+    /// Words outside the band taken because the band had too few words
+    pub band_fallback: usize,
+}
+
+/// Pick `count` words at random, from `band` if given
+///
+/// The caller checks that there are at least `count` candidates.
+pub fn pick<R: Rng + ?Sized>(
+    mut candidates: Vec<Candidate>,
+    count: usize,
+    strategy: RecencyStrategy,
+    band: Option<Band>,
+    rng: &mut R,
+) -> Pick {
+    candidates.shuffle(rng);
+
+    if let Some(band) = band {
+        let (inside, mut outside): (Vec<_>, Vec<_>) = candidates
+            .into_iter()
+            .partition(|candidate| band.distance(candidate.difficulty) == 0.0);
+
+        if inside.len() <= count {
+            // Closest scores first; the shuffle breaks ties
+            outside.sort_by(|a, b| {
+                band.distance(a.difficulty)
+                    .total_cmp(&band.distance(b.difficulty))
+            });
+            let band_fallback = count - inside.len();
+            let chosen: Vec<Candidate> = inside
+                .into_iter()
+                .chain(outside.into_iter().take(band_fallback))
+                .collect();
+            let recent_fallback = match strategy {
+                RecencyStrategy::Exclude => chosen
+                    .iter()
+                    .filter(|candidate| candidate.is_recent())
+                    .count(),
+                RecencyStrategy::Downweight => 0,
+            };
+
+            return Pick {
+                words: chosen.into_iter().map(|candidate| candidate.word).collect(),
+                recent_fallback,
+                band_fallback,
+            };
+        }
+
+        candidates = inside;
+    }
+
+    match strategy {
+        RecencyStrategy::Exclude => {
+            let (fresh, mut recent): (Vec<_>, Vec<_>) = candidates
+                .into_iter()
+                .partition(|candidate| !candidate.is_recent());
+            let fallback = count.saturating_sub(fresh.len());
+            // Least recently played first; the shuffle breaks ties
+            recent.sort_by_key(|candidate| std::cmp::Reverse(candidate.last_played));
+
+            Pick {
+                words: fresh
+                    .into_iter()
+                    .chain(recent)
+                    .take(count)
+                    .map(|candidate| candidate.word)
+                    .collect(),
+                recent_fallback: fallback,
+                band_fallback: 0,
+            }
+        }
+        RecencyStrategy::Downweight => {
+            let words = candidates
+                .choose_multiple_weighted(rng, count, |candidate| {
+                    DOWNWEIGHT_FACTOR
+                        .powi(i32::try_from(candidate.recent_plays).unwrap_or(i32::MAX))
+                })
+                .map(|chosen| chosen.map(|candidate| candidate.word.clone()).collect())
+                // Weights are always positive and finite
+                .unwrap_or_default();
+
+            Pick {
+                words,
+                recent_fallback: 0,
+                band_fallback: 0,
+            }
+        }
+    }
+}
+
+/// Deal the words of one length to the participants, `quota` each
+///
+/// Hardest words go first, each to the participant with the lowest total
+/// difficulty so far among those still short of the quota. `totals` carries
+/// the running totals from one length to the next, so the participants end
+/// up with comparable games.
+pub fn distribute(mut words: Vec<(Word, f64)>, quota: usize, totals: &mut [f64]) -> Vec<Vec<Word>> {
+    let mut dealt: Vec<Vec<Word>> = vec![Vec::with_capacity(quota); totals.len()];
+    words.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    for (word, score) in words {
+        let Some(participant) = (0..totals.len())
+            .filter(|&index| dealt[index].len() < quota)
+            .min_by(|&a, &b| totals[a].total_cmp(&totals[b]))
+        else {
+            break;
+        };
+        totals[participant] += score;
+        dealt[participant].push(word);
+    }
+
+    dealt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn candidate(id: i32, last_played: Option<usize>) -> Candidate {
+        Candidate {
+            word: Word {
+                id,
+                category_id: 1,
+                word: format!("W{}", id),
+                letter_count: 4,
+                hint: String::new(),
+                created_at: String::new(),
+            },
+            recent_plays: u32::from(last_played.is_some()),
+            last_played,
+            difficulty: UNPLAYED_SCORE,
+        }
+    }
+
+    fn scored(id: i32, difficulty: f64) -> Candidate {
+        Candidate {
+            difficulty,
+            ..candidate(id, None)
+        }
+    }
+
+    fn ids(pick: &Pick) -> Vec<i32> {
+        let mut ids: Vec<i32> = pick.words.iter().map(|word| word.id).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn the_same_seed_draws_the_same_words() {
+        let draw = |seed| {
+            let candidates = (1..=20).map(|id| candidate(id, None)).collect();
+            let pick = pick(
+                candidates,
+                5,
+                RecencyStrategy::Exclude,
+                None,
+                &mut seeded(seed).unwrap(),
+            );
+            pick.words.iter().map(|word| word.id).collect::<Vec<_>>()
+        };
+
+        assert_eq!(draw(42), draw(42));
+        assert_ne!(draw(42), draw(43));
+        assert!(seeded(MAX_SEED + 1).is_err());
+    }
+
+    #[test]
+    fn recent_words_are_left_out_when_possible() {
+        let candidates = vec![
+            candidate(1, Some(0)),
+            candidate(2, None),
+            candidate(3, Some(4)),
+            candidate(4, None),
+        ];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let pick = pick(candidates, 2, RecencyStrategy::Exclude, None, &mut rng);
+
+        assert_eq!(ids(&pick), [2, 4]);
+        assert_eq!(pick.recent_fallback, 0);
+    }
+
+    #[test]
+    fn short_pools_fall_back_to_the_least_recently_played() {
+        let candidates = vec![
+            candidate(1, Some(0)),
+            candidate(2, None),
+            candidate(3, Some(4)),
+            candidate(4, Some(2)),
+        ];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let pick = pick(candidates, 3, RecencyStrategy::Exclude, None, &mut rng);
+
+        assert_eq!(ids(&pick), [2, 3, 4]);
+        assert_eq!(pick.recent_fallback, 2);
+    }
+
+    #[test]
+    fn downweighted_words_are_picked_less_often() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut recent_picks = 0;
+
+        for _ in 0..1000 {
+            let candidates = vec![candidate(1, Some(0)), candidate(2, None)];
+            let pick = pick(candidates, 1, RecencyStrategy::Downweight, None, &mut rng);
+            assert_eq!(pick.words.len(), 1);
+            if pick.words[0].id == 1 {
+                recent_picks += 1;
+            }
+        }
+
+        // Expected share is 0.25 / 1.25 = 20%
+        assert!((100..300).contains(&recent_picks), "{}", recent_picks);
+    }
+
+    #[test]
+    fn words_are_taken_from_the_band() {
+        let candidates = vec![
+            scored(1, 20.0),
+            scored(2, 50.0),
+            scored(3, 75.0),
+            scored(4, 90.0),
+        ];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let pick = pick(
+            candidates,
+            2,
+            RecencyStrategy::Exclude,
+            Some(Band::Hard),
+            &mut rng,
+        );
+
+        assert_eq!(ids(&pick), [3, 4]);
+        assert_eq!(pick.band_fallback, 0);
+    }
+
+    #[test]
+    fn short_bands_fall_back_to_the_closest_scores() {
+        let candidates = vec![
+            Candidate {
+                recent_plays: 1,
+                last_played: Some(0),
+                ..scored(1, 30.0)
+            },
+            scored(2, 45.0),
+            scored(3, 75.0),
+            scored(4, 90.0),
+        ];
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let pick = pick(
+            candidates,
+            2,
+            RecencyStrategy::Exclude,
+            Some(Band::Easy),
+            &mut rng,
+        );
+
+        assert_eq!(ids(&pick), [1, 2]);
+        assert_eq!(pick.band_fallback, 1);
+        assert_eq!(pick.recent_fallback, 1);
+    }
+
+    #[test]
+    fn dealt_words_balance_the_difficulty() {
+        let mut totals = vec![0.0; 2];
+        let words = |scores: &[(i32, f64)]| -> Vec<(Word, f64)> {
+            scores
+                .iter()
+                .map(|&(id, score)| (candidate(id, None).word, score))
+                .collect()
+        };
+
+        let first = distribute(
+            words(&[(1, 90.0), (2, 10.0), (3, 80.0), (4, 20.0)]),
+            2,
+            &mut totals,
+        );
+        let second = distribute(
+            words(&[(5, 70.0), (6, 30.0), (7, 60.0), (8, 40.0)]),
+            2,
+            &mut totals,
+        );
+
+        assert!(first.iter().chain(&second).all(|dealt| dealt.len() == 2));
+        assert_eq!(totals, [200.0, 200.0]);
+    }
+}
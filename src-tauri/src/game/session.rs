@@ -0,0 +1,925 @@
+//! Game session state machine
+//!
+//! Implements the game loop from `docs/guncel-oyun-kurallari.md`:
+//! - `Playing`: the participant's timer runs, letters can be revealed
+//! - `Guessing`: the participant's timer is paused and the guess timer runs
+//! - `WordResolved`: the word has a result, waiting for the next word
+//! - `WaitingNextTurn`: the participant is done, waiting for the host
+//! - `Finished`: every participant is done (or the game was ended)
+//!
+//! Timers advance through [`GameSession::tick`], so the session never reads
+//! the clock itself and can be tested deterministically.
+
+use super::scoring;
+use crate::errors::AppError;
+use crate::models::{Language, RelaxedConstraint, ScoringRules, Word, WordDraw};
+use crate::text;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Game mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameMode {
+    Single,
+    Multi,
+    Team,
+}
+
+impl GameMode {
+    /// Value stored in `game_history.game_mode`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Single => "single",
+            Self::Multi => "multi",
+            Self::Team => "team",
+        }
+    }
+
+    /// Allowed number of participants (players or teams)
+    const fn participant_range(self) -> (usize, usize) {
+        match self {
+            Self::Single => (1, 1),
+            Self::Multi => (2, 6),
+            Self::Team => (2, 4),
+        }
+    }
+}
+
+/// Participant type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParticipantType {
+    Player,
+    Team,
+}
+
+impl ParticipantType {
+    /// Value stored in `game_participants.participant_type`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Player => "player",
+            Self::Team => "team",
+        }
+    }
+}
+
+/// Phase of the game loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GamePhase {
+    Playing,
+    Guessing,
+    WordResolved,
+    WaitingNextTurn,
+    Finished,
+}
+
+/// Result of a single word
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WordOutcome {
+    /// Correct guess, current value added
+    Found,
+    /// Wrong guess, current value subtracted
+    Wrong,
+    /// All letters revealed or skipped, no points
+    Skipped,
+    /// Guess timer expired, current value subtracted
+    Timeout,
+    /// Participant ran out of time before the word was played, no points
+    Unplayed,
+}
+
+impl WordOutcome {
+    /// Value stored in `game_word_results.result`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Found => "found",
+            Self::Wrong => "wrong",
+            Self::Skipped => "skipped",
+            Self::Timeout => "timeout",
+            Self::Unplayed => "unplayed",
+        }
+    }
+}
+
+/// A word being played
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionWord {
+    pub id: i32,
+    pub word: String,
+    pub hint: String,
+    pub letter_count: i32,
+    /// Letters of the word (grapheme clusters), indexed like `revealed`
+    pub letters: Vec<String>,
+    /// Reveal state of each letter
+    pub revealed: Vec<bool>,
+    pub letters_revealed: i32,
+    pub has_made_guess: bool,
+    pub result: Option<WordOutcome>,
+    pub points_earned: i32,
+    /// Letter indexes in the order the backend reveals them
+    #[serde(skip)]
+    reveal_order: Vec<usize>,
+}
+
+impl SessionWord {
+    fn new(word: Word, language: Language) -> Self {
+        let letters = text::letters(&word.word);
+        Self {
+            revealed: vec![false; letters.len()],
+            reveal_order: text::reveal_order(&word.word, language),
+            letters,
+            id: word.id,
+            word: word.word,
+            hint: word.hint,
+            letter_count: word.letter_count,
+            letters_revealed: 0,
+            has_made_guess: false,
+            result: None,
+            points_earned: 0,
+        }
+    }
+
+    /// Points at stake for this word right now
+    pub fn current_value(&self, rules: &ScoringRules) -> i32 {
+        scoring::word_value(rules, self.letter_count, self.letters_revealed)
+    }
+
+    fn reveal_all(&mut self) {
+        self.revealed.fill(true);
+    }
+}
+
+/// A player or team taking part in the game
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionParticipant {
+    pub name: String,
+    pub participant_type: ParticipantType,
+    pub score: i32,
+    pub words_found: i32,
+    pub words_wrong: i32,
+    pub words_skipped: i32,
+    pub letters_revealed: i32,
+    pub current_word_index: usize,
+    pub words: Vec<SessionWord>,
+    /// Time used by this participant (each participant has their own timer)
+    pub elapsed_time_seconds: u32,
+    /// Bonus for unused time, included in `score`
+    pub time_bonus: i32,
+}
+
+impl SessionParticipant {
+    /// Whether every word of this participant has a result
+    pub fn is_complete(&self) -> bool {
+        self.words.iter().all(|word| word.result.is_some())
+    }
+}
+
+/// Everything needed to start a session (words are passed separately)
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    pub id: String,
+    pub category_id: i32,
+    pub category_name: String,
+    pub category_emoji: String,
+    /// Language of the category (decides the letter reveal order)
+    pub language: Language,
+    pub mode: GameMode,
+    pub participant_names: Vec<String>,
+    /// Time each participant has (global timer)
+    pub total_time_seconds: u32,
+    /// Time allowed for a guess (guess timer)
+    pub guess_time_seconds: u32,
+    pub scoring: ScoringRules,
+    /// Preset the rules were taken from (recorded in the game history)
+    pub scoring_preset_id: Option<i32>,
+    pub started_at: String,
+}
+
+/// Complete state of a running game
+#[derive(Debug, Clone, Serialize)]
+pub struct GameSession {
+    pub id: String,
+    pub category_id: i32,
+    pub category_name: String,
+    pub category_emoji: String,
+    pub language: Language,
+    pub mode: GameMode,
+    pub phase: GamePhase,
+    pub is_paused: bool,
+    pub participants: Vec<SessionParticipant>,
+    pub active_participant_index: usize,
+    pub total_time_seconds: u32,
+    pub guess_time_seconds: u32,
+    pub guess_time_remaining: u32,
+    pub scoring: ScoringRules,
+    pub scoring_preset_id: Option<i32>,
+    pub started_at: String,
+    /// Word selection constraints that had to be dropped, for every participant
+    pub relaxed_constraints: Vec<RelaxedConstraint>,
+    /// How the words were drawn, to replay the game
+    pub word_draw: Option<WordDraw>,
+    /// Word set the words come from instead of a draw
+    pub word_set_id: Option<i32>,
+    pub word_set_name: Option<String>,
+    /// Database file the game was started on; its IDs are only valid there
+    #[serde(skip)]
+    pub database_path: PathBuf,
+}
+
+impl GameSession {
+    /// Start a session, `words[i]` being the words of the i-th participant
+    pub fn new(config: SessionConfig, words: Vec<Vec<Word>>) -> Result<Self, AppError> {
+        let (min, max) = config.mode.participant_range();
+        let count = config.participant_names.len();
+        if count < min || count > max {
+            return Err(AppError::ValidationError(format!(
+                "Bu mod için {}-{} katılımcı gerekli ({} verildi)",
+                min, max, count
+            )));
+        }
+        if words.len() != count {
+            return Err(AppError::ValidationError(
+                "Her katılımcı için bir kelime listesi gerekli".to_string(),
+            ));
+        }
+        if config.total_time_seconds == 0 || config.guess_time_seconds == 0 {
+            return Err(AppError::ValidationError(
+                "Süreler sıfırdan büyük olmalı".to_string(),
+            ));
+        }
+        scoring::validate_rules(&config.scoring)?;
+
+        let participant_type = if config.mode == GameMode::Team {
+            ParticipantType::Team
+        } else {
+            ParticipantType::Player
+        };
+
+        let mut participants = Vec::with_capacity(count);
+        for (name, words) in config.participant_names.into_iter().zip(words) {
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                return Err(AppError::ValidationError(
+                    "Katılımcı adı boş olamaz".to_string(),
+                ));
+            }
+            if words.is_empty() {
+                return Err(AppError::ValidationError(format!(
+                    "'{}' için kelime seçilmedi",
+                    name
+                )));
+            }
+
+            participants.push(SessionParticipant {
+                name,
+                participant_type,
+                score: 0,
+                words_found: 0,
+                words_wrong: 0,
+                words_skipped: 0,
+                letters_revealed: 0,
+                current_word_index: 0,
+                words: words
+                    .into_iter()
+                    .map(|word| SessionWord::new(word, config.language))
+                    .collect(),
+                elapsed_time_seconds: 0,
+                time_bonus: 0,
+            });
+        }
+
+        Ok(Self {
+            id: config.id,
+            category_id: config.category_id,
+            category_name: config.category_name,
+            category_emoji: config.category_emoji,
+            language: config.language,
+            mode: config.mode,
+            phase: GamePhase::Playing,
+            is_paused: false,
+            participants,
+            active_participant_index: 0,
+            total_time_seconds: config.total_time_seconds,
+            guess_time_seconds: config.guess_time_seconds,
+            guess_time_remaining: 0,
+            scoring: config.scoring,
+            scoring_preset_id: config.scoring_preset_id,
+            started_at: config.started_at,
+            relaxed_constraints: Vec::new(),
+            word_draw: None,
+            word_set_id: None,
+            word_set_name: None,
+            database_path: PathBuf::new(),
+        })
+    }
+
+    /// The participant whose turn it is
+    pub fn active_participant(&self) -> &SessionParticipant {
+        &self.participants[self.active_participant_index]
+    }
+
+    /// The word currently on screen
+    pub fn current_word(&self) -> &SessionWord {
+        let participant = self.active_participant();
+        &participant.words[participant.current_word_index]
+    }
+
+    /// Whether any timer is running
+    pub fn is_clock_running(&self) -> bool {
+        !self.is_paused && matches!(self.phase, GamePhase::Playing | GamePhase::Guessing)
+    }
+
+    /// Advance the timers by `seconds`
+    ///
+    /// In `Playing` the active participant's time runs; when it is used up the
+    /// remaining words are marked as unplayed (no points). In `Guessing` only
+    /// the guess timer runs; when it expires the guess counts as wrong.
+    pub fn tick(&mut self, seconds: u32) {
+        for _ in 0..seconds {
+            if !self.is_clock_running() {
+                break;
+            }
+
+            match self.phase {
+                GamePhase::Playing => {
+                    let total = self.total_time_seconds;
+                    let participant = self.active_participant_mut();
+                    participant.elapsed_time_seconds += 1;
+                    if participant.elapsed_time_seconds >= total {
+                        self.time_up();
+                    }
+                }
+                GamePhase::Guessing => {
+                    self.guess_time_remaining = self.guess_time_remaining.saturating_sub(1);
+                    if self.guess_time_remaining == 0 {
+                        self.resolve_word(WordOutcome::Timeout);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Reveal one letter of the current word
+    ///
+    /// Revealing the last hidden letter resolves the word as skipped (0 points).
+    pub fn reveal_letter(&mut self, letter_index: usize) -> Result<(), AppError> {
+        self.require_phase(GamePhase::Playing)?;
+
+        let word = self.current_word();
+        if letter_index >= word.revealed.len() {
+            return Err(AppError::ValidationError(format!(
+                "Geçersiz harf konumu: {}",
+                letter_index
+            )));
+        }
+        if word.revealed[letter_index] {
+            return Err(AppError::ValidationError("Bu harf zaten açık".to_string()));
+        }
+
+        let participant = self.active_participant_mut();
+        participant.letters_revealed += 1;
+        let word = current_word_mut(participant);
+        word.revealed[letter_index] = true;
+        word.letters_revealed += 1;
+
+        if word.revealed.iter().all(|&revealed| revealed) {
+            self.resolve_word(WordOutcome::Skipped);
+        }
+
+        Ok(())
+    }
+
+    /// Reveal the next letter in the language's reveal order
+    ///
+    /// Common letters of the language are revealed before rare ones.
+    pub fn reveal_next_letter(&mut self) -> Result<(), AppError> {
+        let word = self.current_word();
+        let index = word
+            .reveal_order
+            .iter()
+            .copied()
+            .find(|&index| !word.revealed[index])
+            .ok_or_else(|| AppError::ValidationError("Açılacak harf kalmadı".to_string()))?;
+
+        self.reveal_letter(index)
+    }
+
+    /// Enter guess mode: the participant's timer pauses and the guess timer starts
+    pub fn start_guess(&mut self) -> Result<(), AppError> {
+        self.require_phase(GamePhase::Playing)?;
+
+        current_word_mut(self.active_participant_mut()).has_made_guess = true;
+        self.guess_time_remaining = self.guess_time_seconds;
+        self.phase = GamePhase::Guessing;
+
+        Ok(())
+    }
+
+    /// Resolve the guess as correct (+value) or wrong (-value)
+    pub fn submit_guess(&mut self, is_correct: bool) -> Result<(), AppError> {
+        self.require_phase(GamePhase::Guessing)?;
+
+        self.resolve_word(if is_correct {
+            WordOutcome::Found
+        } else {
+            WordOutcome::Wrong
+        });
+
+        Ok(())
+    }
+
+    /// The guess timer ran out: treated as a wrong answer
+    pub fn guess_timeout(&mut self) -> Result<(), AppError> {
+        self.require_phase(GamePhase::Guessing)?;
+        self.resolve_word(WordOutcome::Timeout);
+
+        Ok(())
+    }
+
+    /// Give up on the current word without guessing (0 points)
+    pub fn skip_word(&mut self) -> Result<(), AppError> {
+        self.require_phase(GamePhase::Playing)?;
+        self.resolve_word(WordOutcome::Skipped);
+
+        Ok(())
+    }
+
+    /// Move on from a resolved word
+    pub fn next_word(&mut self) -> Result<(), AppError> {
+        self.require_phase(GamePhase::WordResolved)?;
+
+        let (total, rules) = (self.total_time_seconds, self.scoring);
+        let participant = self.active_participant_mut();
+        if participant.current_word_index + 1 < participant.words.len() {
+            participant.current_word_index += 1;
+        } else {
+            // All words played before the time ran out
+            let remaining = total.saturating_sub(participant.elapsed_time_seconds);
+            participant.time_bonus = scoring::time_bonus(&rules, remaining);
+            participant.score = participant.score.saturating_add(participant.time_bonus);
+        }
+
+        self.continue_turn();
+
+        Ok(())
+    }
+
+    /// Hand over to the next participant that still has words to play
+    pub fn next_participant(&mut self) -> Result<(), AppError> {
+        self.require_phase(GamePhase::WaitingNextTurn)?;
+
+        let count = self.participants.len();
+        let next = (1..=count)
+            .map(|offset| (self.active_participant_index + offset) % count)
+            .find(|&index| !self.participants[index].is_complete());
+
+        match next {
+            Some(index) => {
+                self.active_participant_index = index;
+                self.phase = GamePhase::Playing;
+            }
+            None => self.phase = GamePhase::Finished,
+        }
+
+        Ok(())
+    }
+
+    /// Stop all timers
+    pub fn pause(&mut self) -> Result<(), AppError> {
+        self.require_not_finished()?;
+        self.is_paused = true;
+
+        Ok(())
+    }
+
+    /// Restart the timers
+    pub fn resume(&mut self) -> Result<(), AppError> {
+        self.require_not_finished()?;
+        self.is_paused = false;
+
+        Ok(())
+    }
+
+    /// End the game early; words that were not played get no result
+    pub fn end(&mut self) -> Result<(), AppError> {
+        self.require_not_finished()?;
+        self.phase = GamePhase::Finished;
+        self.is_paused = false;
+        self.guess_time_remaining = 0;
+
+        Ok(())
+    }
+
+    fn active_participant_mut(&mut self) -> &mut SessionParticipant {
+        &mut self.participants[self.active_participant_index]
+    }
+
+    fn require_phase(&self, phase: GamePhase) -> Result<(), AppError> {
+        if self.is_paused {
+            return Err(AppError::ValidationError("Oyun duraklatıldı".to_string()));
+        }
+        if self.phase != phase {
+            return Err(AppError::ValidationError(format!(
+                "Bu işlem şu anda yapılamaz (oyun durumu: {:?})",
+                self.phase
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn require_not_finished(&self) -> Result<(), AppError> {
+        if self.phase == GamePhase::Finished {
+            return Err(AppError::ValidationError("Oyun bitti".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Score the current word and reveal it
+    fn resolve_word(&mut self, outcome: WordOutcome) {
+        let rules = self.scoring;
+        let participant = self.active_participant_mut();
+        let word = current_word_mut(participant);
+        let points = scoring::outcome_points(
+            &rules,
+            outcome,
+            word.current_value(&rules),
+            word.letters_revealed,
+        );
+
+        match outcome {
+            WordOutcome::Found => participant.words_found += 1,
+            WordOutcome::Wrong | WordOutcome::Timeout => participant.words_wrong += 1,
+            WordOutcome::Skipped => participant.words_skipped += 1,
+            WordOutcome::Unplayed => {}
+        }
+        participant.score = participant.score.saturating_add(points);
+
+        let word = current_word_mut(participant);
+        word.result = Some(outcome);
+        word.points_earned = points;
+        word.reveal_all();
+
+        self.guess_time_remaining = 0;
+        self.phase = GamePhase::WordResolved;
+    }
+
+    /// The active participant's time is used up: remaining words are left unplayed
+    fn time_up(&mut self) {
+        for word in &mut self.active_participant_mut().words {
+            if word.result.is_none() {
+                word.result = Some(WordOutcome::Unplayed);
+                word.reveal_all();
+            }
+        }
+
+        self.continue_turn();
+    }
+
+    /// Decide what follows once the current word is done
+    fn continue_turn(&mut self) {
+        self.phase = if self
+            .participants
+            .iter()
+            .all(SessionParticipant::is_complete)
+        {
+            GamePhase::Finished
+        } else if self.active_participant().is_complete() {
+            GamePhase::WaitingNextTurn
+        } else {
+            GamePhase::Playing
+        };
+    }
+}
+
+fn current_word_mut(participant: &mut SessionParticipant) -> &mut SessionWord {
+    let index = participant.current_word_index;
+    &mut participant.words[index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(id: i32, text: &str) -> Word {
+        Word {
+            id,
+            category_id: 1,
+            word: text.to_string(),
+            letter_count: i32::try_from(text.chars().count()).unwrap(),
+            hint: format!("ipucu {}", id),
+            created_at: String::new(),
+        }
+    }
+
+    fn session(mode: GameMode, names: &[&str], words_each: &[&str]) -> GameSession {
+        session_with_rules(mode, names, words_each, ScoringRules::default())
+    }
+
+    fn session_with_rules(
+        mode: GameMode,
+        names: &[&str],
+        words_each: &[&str],
+        scoring: ScoringRules,
+    ) -> GameSession {
+        let mut next_id = 0;
+        let words = names
+            .iter()
+            .map(|_| {
+                words_each
+                    .iter()
+                    .map(|text| {
+                        next_id += 1;
+                        word(next_id, text)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        GameSession::new(
+            SessionConfig {
+                id: "test".to_string(),
+                category_id: 1,
+                category_name: "Test".to_string(),
+                category_emoji: "🧪".to_string(),
+                language: Language::Tr,
+                mode,
+                participant_names: names.iter().map(ToString::to_string).collect(),
+                total_time_seconds: 300,
+                guess_time_seconds: 30,
+                scoring,
+                scoring_preset_id: None,
+                started_at: String::new(),
+            },
+            words,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn correct_guess_scores_remaining_letters() {
+        let mut game = session(GameMode::Single, &["Ali"], &["KALEM", "ELMA"]);
+
+        game.reveal_letter(0).unwrap();
+        game.start_guess().unwrap();
+        game.submit_guess(true).unwrap();
+
+        let participant = game.active_participant();
+        assert_eq!(participant.score, 400);
+        assert_eq!(participant.words_found, 1);
+        assert_eq!(participant.letters_revealed, 1);
+        assert_eq!(game.current_word().result, Some(WordOutcome::Found));
+        assert!(game.current_word().revealed.iter().all(|&r| r));
+        assert_eq!(game.phase, GamePhase::WordResolved);
+    }
+
+    #[test]
+    fn wrong_guess_and_guess_timeout_subtract_value() {
+        let mut game = session(GameMode::Single, &["Ali"], &["KALEM", "ELMA"]);
+
+        game.start_guess().unwrap();
+        game.submit_guess(false).unwrap();
+        assert_eq!(game.active_participant().score, -500);
+
+        game.next_word().unwrap();
+        game.reveal_letter(1).unwrap();
+        game.start_guess().unwrap();
+        game.guess_timeout().unwrap();
+
+        let participant = game.active_participant();
+        assert_eq!(participant.score, -800);
+        assert_eq!(participant.words_wrong, 2);
+        assert_eq!(game.current_word().result, Some(WordOutcome::Timeout));
+    }
+
+    #[test]
+    fn revealing_every_letter_skips_without_points() {
+        let mut game = session(GameMode::Single, &["Ali"], &["ELMA", "KALEM"]);
+
+        for index in 0..4 {
+            game.reveal_letter(index).unwrap();
+        }
+
+        assert_eq!(game.current_word().result, Some(WordOutcome::Skipped));
+        assert_eq!(game.current_word().points_earned, 0);
+        assert_eq!(game.active_participant().words_skipped, 1);
+        assert_eq!(game.phase, GamePhase::WordResolved);
+    }
+
+    #[test]
+    fn next_letter_follows_the_language_reveal_order() {
+        let mut game = session(GameMode::Single, &["Ali"], &["ŞEKER"]);
+        assert_eq!(game.current_word().letters.len(), 5);
+
+        game.reveal_next_letter().unwrap();
+        game.reveal_next_letter().unwrap();
+        assert_eq!(
+            game.current_word().revealed,
+            vec![false, true, false, true, false]
+        );
+
+        for _ in 0..3 {
+            game.reveal_next_letter().unwrap();
+        }
+        assert_eq!(game.current_word().result, Some(WordOutcome::Skipped));
+        assert!(game.reveal_next_letter().is_err());
+    }
+
+    #[test]
+    fn actions_are_rejected_in_the_wrong_phase() {
+        let mut game = session(GameMode::Single, &["Ali"], &["ELMA"]);
+
+        assert!(game.submit_guess(true).is_err());
+        assert!(game.next_word().is_err());
+
+        game.reveal_letter(2).unwrap();
+        assert!(game.reveal_letter(2).is_err());
+        assert!(game.reveal_letter(10).is_err());
+
+        game.start_guess().unwrap();
+        assert!(game.reveal_letter(0).is_err());
+        assert!(game.skip_word().is_err());
+
+        game.pause().unwrap();
+        assert!(game.submit_guess(true).is_err());
+        game.resume().unwrap();
+        game.submit_guess(true).unwrap();
+        assert_eq!(game.active_participant().score, 300);
+    }
+
+    #[test]
+    fn guess_mode_pauses_the_participant_timer() {
+        let mut game = session(GameMode::Single, &["Ali"], &["ELMA", "KALEM"]);
+
+        game.tick(10);
+        game.start_guess().unwrap();
+        game.tick(5);
+
+        assert_eq!(game.active_participant().elapsed_time_seconds, 10);
+        assert_eq!(game.guess_time_remaining, 25);
+
+        game.tick(100);
+        assert_eq!(game.current_word().result, Some(WordOutcome::Timeout));
+        assert_eq!(game.active_participant().score, -400);
+        assert_eq!(game.active_participant().elapsed_time_seconds, 10);
+    }
+
+    #[test]
+    fn paused_and_resolved_games_do_not_tick() {
+        let mut game = session(GameMode::Single, &["Ali"], &["ELMA", "KALEM"]);
+
+        game.pause().unwrap();
+        game.tick(10);
+        game.resume().unwrap();
+        game.skip_word().unwrap();
+        game.tick(10);
+
+        assert_eq!(game.active_participant().elapsed_time_seconds, 0);
+    }
+
+    #[test]
+    fn running_out_of_time_hands_over_to_the_next_participant() {
+        let mut game = session(GameMode::Multi, &["Ali", "Ayşe"], &["ELMA", "KALEM"]);
+
+        game.tick(300);
+
+        assert_eq!(game.phase, GamePhase::WaitingNextTurn);
+        let first = &game.participants[0];
+        assert_eq!(first.score, 0);
+        assert_eq!(first.words_wrong, 0);
+        assert!(first
+            .words
+            .iter()
+            .all(|word| word.result == Some(WordOutcome::Unplayed)));
+
+        game.next_participant().unwrap();
+        assert_eq!(game.active_participant_index, 1);
+        assert_eq!(game.phase, GamePhase::Playing);
+
+        game.skip_word().unwrap();
+        game.next_word().unwrap();
+        game.start_guess().unwrap();
+        game.submit_guess(true).unwrap();
+        game.next_word().unwrap();
+
+        assert_eq!(game.phase, GamePhase::Finished);
+        assert_eq!(game.participants[1].score, 500);
+    }
+
+    #[test]
+    fn participant_count_depends_on_mode() {
+        let config = |mode, names: &[&str]| SessionConfig {
+            id: String::new(),
+            category_id: 1,
+            category_name: String::new(),
+            category_emoji: String::new(),
+            language: Language::Tr,
+            mode,
+            participant_names: names.iter().map(ToString::to_string).collect(),
+            total_time_seconds: 300,
+            guess_time_seconds: 30,
+            scoring: ScoringRules::default(),
+            scoring_preset_id: None,
+            started_at: String::new(),
+        };
+        let words = |count| vec![vec![word(1, "ELMA")]; count];
+
+        assert!(GameSession::new(config(GameMode::Single, &["A", "B"]), words(2)).is_err());
+        assert!(GameSession::new(config(GameMode::Multi, &["A"]), words(1)).is_err());
+        assert!(GameSession::new(config(GameMode::Team, &["A", " "]), words(2)).is_err());
+        assert!(GameSession::new(config(GameMode::Team, &["A", "B"]), words(2)).is_ok());
+    }
+
+    #[test]
+    fn penalty_factors_scale_wrong_answers_and_timeouts() {
+        let no_negative = ScoringRules {
+            wrong_penalty_factor: 0.0,
+            timeout_penalty_factor: 0.0,
+            ..ScoringRules::default()
+        };
+        let mut game =
+            session_with_rules(GameMode::Single, &["Ali"], &["KALEM", "ELMA"], no_negative);
+
+        game.start_guess().unwrap();
+        game.submit_guess(false).unwrap();
+        game.next_word().unwrap();
+        game.start_guess().unwrap();
+        game.tick(30);
+        assert_eq!(game.active_participant().score, 0);
+
+        let harsh = ScoringRules {
+            points_per_letter: 50,
+            wrong_penalty_factor: 1.5,
+            ..ScoringRules::default()
+        };
+        let mut game = session_with_rules(GameMode::Single, &["Ali"], &["KALEM"], harsh);
+
+        game.start_guess().unwrap();
+        game.submit_guess(false).unwrap();
+        assert_eq!(game.active_participant().score, -375);
+    }
+
+    #[test]
+    fn bonuses_reward_no_reveals_and_unused_time() {
+        let rules = ScoringRules {
+            no_reveal_bonus: 200,
+            time_bonus_per_second: 2,
+            ..ScoringRules::default()
+        };
+        let mut game = session_with_rules(GameMode::Single, &["Ali"], &["ELMA", "KALEM"], rules);
+
+        game.tick(40);
+        game.start_guess().unwrap();
+        game.submit_guess(true).unwrap();
+        assert_eq!(game.active_participant().score, 600);
+
+        game.next_word().unwrap();
+        game.reveal_letter(0).unwrap();
+        game.tick(60);
+        game.start_guess().unwrap();
+        game.submit_guess(true).unwrap();
+        game.next_word().unwrap();
+
+        let participant = game.active_participant();
+        assert_eq!(participant.time_bonus, 400);
+        assert_eq!(participant.score, 600 + 400 + 400);
+        assert_eq!(game.phase, GamePhase::Finished);
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let rules = ScoringRules {
+            points_per_letter: 0,
+            ..ScoringRules::default()
+        };
+        assert!(scoring::validate_rules(&rules).is_err());
+
+        let rules = ScoringRules {
+            wrong_penalty_factor: -1.0,
+            ..ScoringRules::default()
+        };
+        assert!(scoring::validate_rules(&rules).is_err());
+        assert!(scoring::validate_rules(&ScoringRules::default()).is_ok());
+    }
+
+    #[test]
+    fn ranking_breaks_ties_by_letters_then_time() {
+        let mut game = session(GameMode::Multi, &["A", "B", "C", "D"], &["ELMA"]);
+        let scores = [(400, 0, 50), (400, 1, 10), (400, 0, 50), (500, 3, 90)];
+        for (participant, (score, letters, time)) in game.participants.iter_mut().zip(scores) {
+            participant.score = score;
+            participant.letters_revealed = letters;
+            participant.elapsed_time_seconds = time;
+        }
+
+        assert_eq!(
+            scoring::rank_participants(&game.participants),
+            vec![2, 4, 2, 1]
+        );
+    }
+}
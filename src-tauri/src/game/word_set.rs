@@ -0,0 +1,188 @@
+//! Curated word sets ("question packs")
+//!
+//! A word set is an ordered list of words prepared in advance instead of a
+//! random draw. Every `total_words` consecutive words of the set are the
+//! words of one participant, played in the order of the set, so a set for
+//! four teams of the standard format holds 56 words.
+
+use super::format::length_quotas;
+use crate::errors::AppError;
+use crate::models::{GameFormat, WordOrder};
+use std::collections::{BTreeMap, HashMap};
+
+/// Number of participants a set of `word_count` words is enough for
+pub fn participant_capacity(format: &GameFormat, word_count: usize) -> usize {
+    usize::try_from(format.total_words)
+        .ok()
+        .filter(|&total| total > 0)
+        .map_or(0, |total| word_count / total)
+}
+
+/// Category a game played with words of `category_ids` (in set order) is saved under
+///
+/// A `requested` category must be one of them; otherwise the category most
+/// words come from is used, the earliest in the set on a tie.
+pub fn game_category(category_ids: &[i32], requested: Option<i32>) -> Result<i32, AppError> {
+    if let Some(category_id) = requested {
+        return if category_ids.contains(&category_id) {
+            Ok(category_id)
+        } else {
+            Err(AppError::ValidationError(
+                "Kelime setinde bu kategoriden kelime yok".to_string(),
+            ))
+        };
+    }
+
+    let mut counts: HashMap<i32, usize> = HashMap::new();
+    for &category_id in category_ids {
+        *counts.entry(category_id).or_default() += 1;
+    }
+
+    let (mut best, mut best_count) = (None, 0);
+    for &category_id in category_ids {
+        if counts[&category_id] > best_count {
+            best = Some(category_id);
+            best_count = counts[&category_id];
+        }
+    }
+
+    best.ok_or_else(|| AppError::ValidationError("Kelime setinde kelime yok".to_string()))
+}
+
+/// Reasons a set with words of `letter_counts` (in set order) cannot be
+/// played with `format`, empty if it can
+pub fn word_set_issues(format: &GameFormat, letter_counts: &[i32]) -> Vec<String> {
+    let Some(total) = usize::try_from(format.total_words)
+        .ok()
+        .filter(|&total| total > 0)
+    else {
+        return vec!["Oyun formatında kelime yok".to_string()];
+    };
+    if letter_counts.is_empty() {
+        return vec!["Kelime setinde kelime yok".to_string()];
+    }
+
+    let mut issues = Vec::new();
+    if letter_counts.len() % total != 0 {
+        issues.push(format!(
+            "Kelime sayısı ({}) formattaki {} kelimenin katı olmalı",
+            letter_counts.len(),
+            total
+        ));
+    }
+
+    let quotas: BTreeMap<i32, usize> = length_quotas(format)
+        .into_iter()
+        .map(|(letter_count, quota)| (letter_count, usize::try_from(quota).unwrap_or(0)))
+        .collect();
+
+    for (index, chunk) in letter_counts.chunks(total).enumerate() {
+        let participant = index + 1;
+
+        let mut counts: BTreeMap<i32, usize> = BTreeMap::new();
+        for &letter_count in chunk {
+            *counts.entry(letter_count).or_default() += 1;
+        }
+
+        for (&letter_count, &quota) in &quotas {
+            let count = counts.get(&letter_count).copied().unwrap_or(0);
+            if count != quota {
+                issues.push(format!(
+                    "{}. katılımcı: {} harfli {} kelime olmalı, {} var",
+                    participant, letter_count, quota, count
+                ));
+            }
+        }
+        for (&letter_count, &count) in &counts {
+            if !quotas.contains_key(&letter_count) {
+                issues.push(format!(
+                    "{}. katılımcı: {} harfli {} kelime formatta yok",
+                    participant, letter_count, count
+                ));
+            }
+        }
+
+        if format.order == WordOrder::Ascending && chunk.windows(2).any(|pair| pair[0] > pair[1]) {
+            issues.push(format!(
+                "{}. katılımcı: kelimeler harf sayısına göre artan sırada olmalı",
+                participant
+            ));
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standard_words() -> Vec<i32> {
+        (4..=10).flat_map(|length| [length, length]).collect()
+    }
+
+    #[test]
+    fn sets_matching_the_format_have_no_issues() {
+        let format = GameFormat::default();
+        let mut two_participants = standard_words();
+        two_participants.extend(standard_words());
+
+        assert!(word_set_issues(&format, &standard_words()).is_empty());
+        assert!(word_set_issues(&format, &two_participants).is_empty());
+        assert_eq!(participant_capacity(&format, two_participants.len()), 2);
+    }
+
+    #[test]
+    fn wrong_lengths_are_listed_per_participant() {
+        let mut words = standard_words();
+        words[13] = 11;
+
+        assert_eq!(
+            word_set_issues(&GameFormat::default(), &words),
+            [
+                "1. katılımcı: 10 harfli 2 kelime olmalı, 1 var",
+                "1. katılımcı: 11 harfli 1 kelime formatta yok",
+            ]
+        );
+    }
+
+    #[test]
+    fn incomplete_sets_are_rejected() {
+        let format = GameFormat::default();
+        let words = standard_words();
+
+        assert_eq!(
+            word_set_issues(&format, &words[..10])
+                .first()
+                .map(String::as_str),
+            Some("Kelime sayısı (10) formattaki 14 kelimenin katı olmalı")
+        );
+        assert_eq!(participant_capacity(&format, 10), 0);
+        assert_eq!(word_set_issues(&format, &[]), ["Kelime setinde kelime yok"]);
+    }
+
+    #[test]
+    fn games_are_saved_under_the_main_category_of_the_set() {
+        assert_eq!(game_category(&[3, 1, 1, 3, 2, 1], None).unwrap(), 1);
+        assert_eq!(game_category(&[3, 1, 1, 3], None).unwrap(), 3);
+        assert_eq!(game_category(&[3, 1, 1, 3], Some(1)).unwrap(), 1);
+        assert!(game_category(&[3, 1], Some(2)).is_err());
+        assert!(game_category(&[], None).is_err());
+    }
+
+    #[test]
+    fn ascending_formats_need_ascending_sets() {
+        let mut words = standard_words();
+        words.reverse();
+        let random = GameFormat {
+            order: WordOrder::Random,
+            ..GameFormat::default()
+        };
+
+        assert_eq!(
+            word_set_issues(&GameFormat::default(), &words),
+            ["1. katılımcı: kelimeler harf sayısına göre artan sırada olmalı"]
+        );
+        assert!(word_set_issues(&random, &words).is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+//! CSV and TSV files
+
+use super::TableRow;
+use crate::errors::AppError;
+
+/// Read all records of a delimited file
+///
+/// Without an explicit delimiter the one used most on the first line wins
+/// (`;` is common in CSV saved by a Turkish Excel, `,` elsewhere).
+pub(super) fn read_table(text: &str, delimiter: Option<u8>) -> Result<Vec<TableRow>, AppError> {
+    let delimiter = delimiter.unwrap_or_else(|| detect_delimiter(text));
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .from_reader(text.as_bytes());
+
+    let bytes = text.as_bytes();
+    // Start of the previous record and its line, so that each record only
+    // counts the newlines since then
+    let (mut offset, mut line) = (0, 1);
+    let mut table = Vec::new();
+    for record in reader.records() {
+        let record =
+            record.map_err(|e| AppError::ValidationError(format!("Dosya okunamadı: {}", e)))?;
+        // The reader's line counter and offsets include skipped blank lines,
+        // so move on to the first character of the record
+        if let Some(position) = record.position() {
+            let start = usize::try_from(position.byte())
+                .unwrap_or(usize::MAX)
+                .clamp(offset, bytes.len());
+            let start = start
+                + bytes[start..]
+                    .iter()
+                    .take_while(|&&byte| byte == b'\r' || byte == b'\n')
+                    .count();
+
+            line += bytes[offset..start]
+                .iter()
+                .filter(|&&byte| byte == b'\n')
+                .count();
+            offset = start;
+        }
+
+        table.push((line, record.iter().map(str::to_string).collect()));
+    }
+
+    Ok(table)
+}
+
+/// Most frequent of `,`, `;` and tab on the first non-empty line
+fn detect_delimiter(text: &str) -> u8 {
+    let first_line = text
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or_default();
+
+    [b',', b';', b'\t']
+        .into_iter()
+        .max_by_key(|&delimiter| first_line.bytes().filter(|&byte| byte == delimiter).count())
+        .unwrap_or(b',')
+}
@@ -0,0 +1,424 @@
+//! Word list import
+//!
+//! Reads word lists from the files teachers actually keep them in and turns
+//! them into [`ImportRow`]s. Nothing is validated against a category here,
+//! that happens when the rows are written (`commands::import`).
+//!
+//! Modules:
+//! - `delimited`: CSV and TSV files
+//! - `spreadsheet`: Excel and OpenDocument workbooks
+//! - `plain_text`: "WORD - hint" lines
+
+mod delimited;
+mod plain_text;
+mod spreadsheet;
+
+use crate::errors::AppError;
+use crate::export;
+use crate::models::{
+    CategoryExportData, ColumnRef, ImportFormat, ImportOptions, ImportRowError, Language,
+};
+use crate::text;
+
+/// Header names recognized as the word column
+const WORD_HEADERS: &[&str] = &["kelime", "sözcük", "word"];
+
+/// Header names recognized as the hint column
+const HINT_HEADERS: &[&str] = &["ipucu", "açıklama", "hint", "clue"];
+
+/// A word read from a file, not validated yet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportRow {
+    /// Line, row or position in the file (1-based)
+    pub line: usize,
+    pub word: String,
+    pub hint: String,
+    /// Letter count stated in the file (JSON exports only)
+    pub letter_count: Option<i32>,
+}
+
+/// Rows read from a file plus the rows that could not be read
+#[derive(Debug, Default)]
+pub struct ParsedFile {
+    pub rows: Vec<ImportRow>,
+    pub errors: Vec<ImportRowError>,
+}
+
+/// Cells of a table row with the row's line number
+type TableRow = (usize, Vec<String>);
+
+/// Read a word list file
+///
+/// The format comes from `options.format` or is detected from the file name
+/// and contents.
+pub fn parse_file(
+    file_name: &str,
+    data: &[u8],
+    options: &ImportOptions,
+) -> Result<ParsedFile, AppError> {
+    let format = options
+        .format
+        .unwrap_or_else(|| detect_format(file_name, data));
+
+    match format {
+        ImportFormat::Csv => {
+            let table = delimited::read_table(&decode_text(data), None)?;
+            rows_from_table(table, options)
+        }
+        ImportFormat::Tsv => {
+            let table = delimited::read_table(&decode_text(data), Some(b'\t'))?;
+            rows_from_table(table, options)
+        }
+        ImportFormat::Xlsx => {
+            let table = spreadsheet::read_table(data, options.sheet.as_deref())?;
+            rows_from_table(table, options)
+        }
+        ImportFormat::Text => Ok(plain_text::read_rows(&decode_text(data))),
+        ImportFormat::Json => {
+            let file = export::open(serde_json::from_str(&decode_text(data))?)?;
+            Ok(rows_from_export(&file.payload.into_category()?))
+        }
+    }
+}
+
+/// Guess the format from the file extension, then from the contents
+pub fn detect_format(file_name: &str, data: &[u8]) -> ImportFormat {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("csv") => return ImportFormat::Csv,
+        Some("tsv" | "tab") => return ImportFormat::Tsv,
+        Some("xlsx" | "xlsm" | "xls" | "ods") => return ImportFormat::Xlsx,
+        Some("txt") => return ImportFormat::Text,
+        Some("json") => return ImportFormat::Json,
+        _ => {}
+    }
+
+    // ZIP (xlsx, ods) and OLE (xls) containers
+    if data.starts_with(b"PK\x03\x04") || data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0]) {
+        return ImportFormat::Xlsx;
+    }
+
+    let text = decode_text(data);
+    if text.trim_start().starts_with('{') {
+        return ImportFormat::Json;
+    }
+
+    // A dash only makes a text line when it comes before any delimiter;
+    // `ELMA,Kırmızı-yeşil meyve` is a CSV row with a dash in the hint
+    let first_line = text.lines().find(|line| !line.trim().is_empty());
+    match first_line {
+        Some(line) if line.contains('\t') => ImportFormat::Tsv,
+        Some(line)
+            if plain_text::split_line(line).is_some_and(|(word, _)| !word.contains([';', ','])) =>
+        {
+            ImportFormat::Text
+        }
+        Some(line) if line.contains(';') || line.contains(',') => ImportFormat::Csv,
+        _ => ImportFormat::Text,
+    }
+}
+
+/// Decode a text file
+///
+/// UTF-8 (with or without BOM) is expected. Anything else is read as
+/// Windows-1254, the encoding Turkish Excel uses when saving CSV.
+fn decode_text(data: &[u8]) -> String {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+
+    match std::str::from_utf8(data) {
+        Ok(text) => text.to_string(),
+        Err(_) => encoding_rs::WINDOWS_1254.decode(data).0.into_owned(),
+    }
+}
+
+/// Turn table rows into word rows using the column mapping
+fn rows_from_table(table: Vec<TableRow>, options: &ImportOptions) -> Result<ParsedFile, AppError> {
+    let mut table = table
+        .into_iter()
+        .filter(|(_, cells)| cells.iter().any(|cell| !cell.trim().is_empty()))
+        .peekable();
+
+    let header = table
+        .peek()
+        .map(|(_, cells)| cells.clone())
+        .unwrap_or_default();
+    let has_header = options.has_header.unwrap_or_else(|| {
+        find_header(&header, WORD_HEADERS).is_some() || find_header(&header, HINT_HEADERS).is_some()
+    });
+    if has_header {
+        table.next();
+    }
+    let header = if has_header { header } else { Vec::new() };
+
+    let word_column = match &options.word_column {
+        Some(column) => resolve_column(column, &header)?,
+        None => find_header(&header, WORD_HEADERS).unwrap_or(0),
+    };
+    let hint_column = match &options.hint_column {
+        Some(column) => Some(resolve_column(column, &header)?),
+        None => find_header(&header, HINT_HEADERS).or_else(|| (word_column == 0).then_some(1)),
+    };
+
+    let mut parsed = ParsedFile::default();
+    for (line, cells) in table {
+        let cell = |index: usize| cells.get(index).map_or("", |cell| cell.trim());
+        let word = cell(word_column);
+        let hint = hint_column.map_or("", cell);
+
+        if word.is_empty() {
+            parsed.errors.push(ImportRowError {
+                line,
+                word: String::new(),
+                reason: "Kelime sütunu boş".to_string(),
+            });
+            continue;
+        }
+
+        parsed.rows.push(ImportRow {
+            line,
+            word: word.to_string(),
+            hint: hint.to_string(),
+            letter_count: None,
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Index of a column given by name or 1-based position
+fn resolve_column(column: &ColumnRef, header: &[String]) -> Result<usize, AppError> {
+    match column {
+        ColumnRef::Position(0) => Err(AppError::ValidationError(
+            "Sütun numarası 1'den başlar".to_string(),
+        )),
+        ColumnRef::Position(position) => Ok(position - 1),
+        ColumnRef::Name(name) => find_header(header, &[name.as_str()]).ok_or_else(|| {
+            AppError::ValidationError(format!("Dosyada \"{}\" sütunu bulunamadı", name))
+        }),
+    }
+}
+
+/// Position of the first header cell matching one of `names`
+///
+/// Case-insensitive under both Turkish and English rules, so "HINT" and
+/// "KELİME" match as well as "Hint" and "kelime".
+fn find_header(header: &[String], names: &[&str]) -> Option<usize> {
+    let key = |name: &str| text::collation_key(name, Language::Tr).replace('ı', "i");
+    let names: Vec<String> = names.iter().map(|name| key(name)).collect();
+
+    header.iter().position(|cell| names.contains(&key(cell)))
+}
+
+/// Rows of a category exported by `export_category_json`
+pub fn rows_from_export(export: &CategoryExportData) -> ParsedFile {
+    let rows = export
+        .words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| ImportRow {
+            line: index + 1,
+            word: word.word.clone(),
+            hint: word.hint.clone(),
+            letter_count: Some(word.letter_count),
+        })
+        .collect();
+
+    ParsedFile {
+        rows,
+        errors: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(file_name: &str, data: &str, options: &ImportOptions) -> ParsedFile {
+        parse_file(file_name, data.as_bytes(), options).unwrap()
+    }
+
+    /// Minimal .xlsx workbook with one "Kelimeler" sheet of `(cell, text)` cells
+    fn xlsx(cells: &[(&str, &str)]) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let mut rows = std::collections::BTreeMap::<u32, String>::new();
+        for (cell, text) in cells {
+            let row = cell
+                .trim_start_matches(char::is_alphabetic)
+                .parse()
+                .unwrap();
+            rows.entry(row).or_default().push_str(&format!(
+                r#"<c r="{}" t="inlineStr"><is><t>{}</t></is></c>"#,
+                cell, text
+            ));
+        }
+        let rows: String = rows
+            .iter()
+            .map(|(row, cells)| format!(r#"<row r="{}">{}</row>"#, row, cells))
+            .collect();
+        let files = [
+            (
+                "[Content_Types].xml",
+                r#"<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#.to_string(),
+            ),
+            (
+                "_rels/.rels",
+                r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_string(),
+            ),
+            (
+                "xl/workbook.xml",
+                r#"<?xml version="1.0" encoding="UTF-8"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Kelimeler" sheetId="1" r:id="rId1"/></sheets></workbook>"#.to_string(),
+            ),
+            (
+                "xl/_rels/workbook.xml.rels",
+                r#"<?xml version="1.0" encoding="UTF-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#.to_string(),
+            ),
+            (
+                "xl/worksheets/sheet1.xml",
+                format!(r#"<?xml version="1.0" encoding="UTF-8"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{}</sheetData></worksheet>"#, rows),
+            ),
+        ];
+
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (name, contents) in files {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(contents.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn words(parsed: &ParsedFile) -> Vec<(usize, &str, &str)> {
+        parsed
+            .rows
+            .iter()
+            .map(|row| (row.line, row.word.as_str(), row.hint.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn formats_are_detected_from_name_then_contents() {
+        assert_eq!(detect_format("liste.CSV", b""), ImportFormat::Csv);
+        assert_eq!(detect_format("liste.xlsx", b""), ImportFormat::Xlsx);
+        assert_eq!(detect_format("liste", b"PK\x03\x04..."), ImportFormat::Xlsx);
+        assert_eq!(detect_format("liste", b"ELMA\tMeyve"), ImportFormat::Tsv);
+        assert_eq!(
+            detect_format("liste", b"ELMA - Meyve, k\xC4\xB1rm\xC4\xB1z\xC4\xB1"),
+            ImportFormat::Text
+        );
+        assert_eq!(detect_format("liste", b"ELMA;Meyve"), ImportFormat::Csv);
+        assert_eq!(
+            detect_format("liste", "ELMA,Kırmızı-yeşil meyve".as_bytes()),
+            ImportFormat::Csv
+        );
+        assert_eq!(
+            detect_format("liste", b" {\"words\": []}"),
+            ImportFormat::Json
+        );
+    }
+
+    #[test]
+    fn csv_header_names_select_the_columns() {
+        let data = "No;İpucu;KELİME\n1;Meyve;elma\n2;Hayvan;\n\n3;\"Renk; koyu\";lacivert\n";
+        let parsed = parse("liste.csv", data, &ImportOptions::default());
+
+        assert_eq!(
+            words(&parsed),
+            vec![(2, "elma", "Meyve"), (5, "lacivert", "Renk; koyu")]
+        );
+        assert_eq!(parsed.errors.len(), 1);
+        assert_eq!(parsed.errors[0].line, 3);
+    }
+
+    #[test]
+    fn csv_line_numbers_count_multiline_cells() {
+        let data = "KELİME,İPUCU\r\nelma,\"Kırmızı\r\nya da yeşil\"\r\n\r\narmut,Meyve\r\n";
+        let parsed = parse("liste.csv", data, &ImportOptions::default());
+
+        assert_eq!(
+            words(&parsed),
+            vec![(2, "elma", "Kırmızı\r\nya da yeşil"), (5, "armut", "Meyve")]
+        );
+    }
+
+    #[test]
+    fn columns_can_be_mapped_by_position_or_name() {
+        let data = "Meyve\telma\nHayvan\taslan\n";
+        let options = ImportOptions {
+            word_column: Some(ColumnRef::Position(2)),
+            hint_column: Some(ColumnRef::Position(1)),
+            ..ImportOptions::default()
+        };
+        let parsed = parse("liste.tsv", data, &options);
+        assert_eq!(
+            words(&parsed),
+            vec![(1, "elma", "Meyve"), (2, "aslan", "Hayvan")]
+        );
+
+        let options = ImportOptions {
+            word_column: Some(ColumnRef::Name("Terim".to_string())),
+            ..ImportOptions::default()
+        };
+        assert!(parse_file("liste.tsv", data.as_bytes(), &options).is_err());
+    }
+
+    #[test]
+    fn xlsx_rows_keep_sheet_line_numbers_and_columns() {
+        // Table starting at B3 with an empty row 5
+        let data = xlsx(&[
+            ("B3", "Kelime"),
+            ("C3", "İpucu"),
+            ("B4", "elma"),
+            ("C4", "Meyve"),
+            ("B6", "aslan"),
+            ("C6", "Hayvan"),
+        ]);
+        let parsed = parse_file("liste.xlsx", &data, &ImportOptions::default()).unwrap();
+        assert_eq!(
+            words(&parsed),
+            vec![(4, "elma", "Meyve"), (6, "aslan", "Hayvan")]
+        );
+
+        // Positions count from column A, not from the first used column
+        let options = ImportOptions {
+            word_column: Some(ColumnRef::Position(2)),
+            hint_column: Some(ColumnRef::Position(3)),
+            sheet: Some("Kelimeler".to_string()),
+            ..ImportOptions::default()
+        };
+        let parsed = parse_file("liste.xlsx", &data, &options).unwrap();
+        assert_eq!(
+            words(&parsed),
+            vec![(4, "elma", "Meyve"), (6, "aslan", "Hayvan")]
+        );
+    }
+
+    #[test]
+    fn text_lines_split_at_the_first_dash() {
+        let data = "# Meyveler\nELMA - Kırmızı ya da yeşil - tatlı\n\nARMUT\nKİRAZ – Dalında çift olur\n - ipucu\n";
+        let parsed = parse("liste.txt", data, &ImportOptions::default());
+
+        assert_eq!(
+            words(&parsed),
+            vec![
+                (2, "ELMA", "Kırmızı ya da yeşil - tatlı"),
+                (4, "ARMUT", ""),
+                (5, "KİRAZ", "Dalında çift olur"),
+            ]
+        );
+        assert_eq!(parsed.errors[0].line, 6);
+    }
+
+    #[test]
+    fn windows_1254_text_is_decoded() {
+        // "ŞEKER - Tatlı" in Windows-1254
+        let data = b"\xDEEKER - Tatl\xFD";
+        let parsed = parse_file("liste.txt", data, &ImportOptions::default()).unwrap();
+        assert_eq!(words(&parsed), vec![(1, "ŞEKER", "Tatlı")]);
+    }
+}
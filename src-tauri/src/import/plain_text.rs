@@ -0,0 +1,53 @@
+//! "WORD - hint" text files
+//!
+//! One word per line, the hint follows the first dash (-, – or —) or tab.
+//! Blank lines and lines starting with `#` are ignored.
+
+use super::{ImportRow, ParsedFile};
+use crate::models::ImportRowError;
+
+/// Read the word rows of a text file
+pub(super) fn read_rows(text: &str) -> ParsedFile {
+    let mut parsed = ParsedFile::default();
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let (word, hint) = split_line(trimmed).unwrap_or((trimmed, ""));
+        if word.is_empty() {
+            parsed.errors.push(ImportRowError {
+                line: line_number,
+                word: String::new(),
+                reason: "Satırda kelime yok".to_string(),
+            });
+            continue;
+        }
+
+        parsed.rows.push(ImportRow {
+            line: line_number,
+            word: word.to_string(),
+            hint: hint.to_string(),
+            letter_count: None,
+        });
+    }
+
+    parsed
+}
+
+/// Split a line into word and hint at the first separator
+///
+/// Words cannot contain a dash, so everything after the first one is hint.
+pub(super) fn split_line(line: &str) -> Option<(&str, &str)> {
+    let (index, separator) = line
+        .char_indices()
+        .find(|&(_, c)| matches!(c, '-' | '–' | '—' | '\t'))?;
+
+    Some((
+        line[..index].trim(),
+        line[index + separator.len_utf8()..].trim(),
+    ))
+}
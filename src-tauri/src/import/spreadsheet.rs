@@ -0,0 +1,40 @@
+//! Excel (.xlsx, .xls) and OpenDocument (.ods) workbooks
+
+use super::TableRow;
+use crate::errors::AppError;
+use calamine::{open_workbook_auto_from_rs, Reader};
+use std::io::Cursor;
+
+/// Read the cells of a worksheet (the first one unless `sheet` is given)
+///
+/// Line numbers are the row numbers shown by the spreadsheet program.
+pub(super) fn read_table(data: &[u8], sheet: Option<&str>) -> Result<Vec<TableRow>, AppError> {
+    let mut workbook = open_workbook_auto_from_rs(Cursor::new(data))
+        .map_err(|e| AppError::ValidationError(format!("Çalışma kitabı okunamadı: {}", e)))?;
+
+    let range = match sheet {
+        Some(name) => workbook.worksheet_range(name).map_err(|_| {
+            AppError::ValidationError(format!("\"{}\" çalışma sayfası bulunamadı", name))
+        })?,
+        None => workbook
+            .worksheet_range_at(0)
+            .ok_or_else(|| AppError::ValidationError("Çalışma kitabı boş".to_string()))?
+            .map_err(|e| AppError::ValidationError(format!("Çalışma sayfası okunamadı: {}", e)))?,
+    };
+
+    // Ranges start at the first used cell, not at A1
+    let (first_row, first_column) = range.start().unwrap_or_default();
+    let padding = vec![String::new(); first_column as usize];
+
+    let table = range
+        .rows()
+        .enumerate()
+        .map(|(index, row)| {
+            let mut cells = padding.clone();
+            cells.extend(row.iter().map(ToString::to_string));
+            (first_row as usize + index + 1, cells)
+        })
+        .collect();
+
+    Ok(table)
+}
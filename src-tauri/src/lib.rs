@@ -2,19 +2,29 @@
 pub mod commands;
 pub mod db;
 pub mod errors;
+pub mod export;
+pub mod game;
+pub mod import;
 pub mod models;
+pub mod report;
+pub mod text;
 
 use tauri::Manager;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Initialize database on startup
-    if let Err(e) = db::init_database() {
-        eprintln!("Failed to initialize database: {}", e);
-        std::process::exit(1);
-    }
+    let db_state = match db::init_database() {
+        Ok(db_state) => db_state,
+        Err(e) => {
+            eprintln!("Failed to initialize database: {}", e);
+            std::process::exit(1);
+        }
+    };
 
     tauri::Builder::default()
+        .manage(db_state)
+        .manage(game::GameSessionState::default())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
@@ -39,6 +49,10 @@ pub fn run() {
                     let _ = window.set_fullscreen(true);
                 }
             }
+
+            // Start automatic backups
+            commands::spawn_backup_scheduler(app.handle().clone());
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -51,21 +65,83 @@ pub fn run() {
             commands::validate_category,
             commands::export_category_json,
             commands::import_category_json,
+            commands::import_words,
+            commands::preview_import_words,
+            commands::preview_import_category_json,
+            commands::export_library,
+            commands::import_library,
+            commands::export_category_to_file,
+            commands::export_library_to_file,
+            commands::import_words_from_file,
+            commands::import_category_from_file,
+            commands::import_library_from_file,
+            commands::export_category_report,
+            commands::export_game_report,
             // Word commands
             commands::get_words_by_category,
             commands::add_word,
             commands::update_word,
             commands::delete_word,
             commands::get_random_words,
+            commands::select_words,
+            commands::allocate_words,
+            commands::get_word_merges,
             commands::validate_category_for_mode,
+            // Statistics commands
+            commands::get_word_statistics,
+            commands::get_word_statistics_by_id,
+            // Word set commands
+            commands::get_word_sets,
+            commands::get_word_set_by_id,
+            commands::create_word_set,
+            commands::update_word_set,
+            commands::delete_word_set,
+            commands::validate_word_set,
             // Settings commands
             commands::get_settings,
             commands::update_setting,
             // Database commands (Task 31)
             commands::backup_database,
+            commands::get_backup_manifest,
             commands::restore_database,
             commands::reset_all_data,
             commands::get_database_size,
+            commands::list_auto_backups,
+            commands::create_auto_backup,
+            commands::preview_auto_backup,
+            commands::delete_auto_backup,
+            // Profile commands
+            commands::get_profiles,
+            commands::get_database_location,
+            commands::create_profile,
+            commands::switch_profile,
+            commands::rename_profile,
+            commands::delete_profile,
+            // Scoring preset commands
+            commands::get_scoring_presets,
+            commands::create_scoring_preset,
+            commands::update_scoring_preset,
+            commands::delete_scoring_preset,
+            // Game format commands
+            commands::get_game_formats,
+            commands::create_game_format,
+            commands::update_game_format,
+            commands::delete_game_format,
+            // Game session commands
+            commands::start_game_session,
+            commands::get_game_session,
+            commands::reveal_letter,
+            commands::start_guess,
+            commands::submit_guess,
+            commands::guess_timeout,
+            commands::skip_word,
+            commands::next_word,
+            commands::next_participant,
+            commands::pause_game,
+            commands::resume_game,
+            commands::end_game,
+            commands::finish_game_session,
+            commands::abandon_game_session,
             // Game history commands
             commands::get_all_game_history,
             commands::get_game_history_by_id,
@@ -74,8 +150,12 @@ pub fn run() {
             commands::get_game_history_stats,
             commands::delete_game_history,
             commands::delete_all_game_history,
-            commands::save_game_to_history,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                commands::run_exit_backup(&app.state::<db::DbState>());
+            }
+        });
 }
@@ -12,6 +12,92 @@ pub struct Category {
     pub is_default: bool,
     pub created_at: String,
     pub updated_at: String,
+    /// Language of the words: decides casing, duplicate matching and reveal order
+    pub language: Language,
+    /// Letters allowed in the category's words
+    pub alphabet: Alphabet,
+    /// Allowed letters when `alphabet` is `custom` (upper-case, no separators)
+    pub custom_letters: Option<String>,
+}
+
+/// Language and alphabet options of `create_category` and `update_category`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CategoryLanguage {
+    pub language: Option<Language>,
+    pub alphabet: Option<Alphabet>,
+    /// Letters of a custom alphabet (e.g. "A B C Ç")
+    pub custom_letters: Option<String>,
+}
+
+/// Language of a category's words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    /// Turkish
+    #[default]
+    Tr,
+    /// English
+    En,
+}
+
+impl Language {
+    /// Value stored in `categories.language` and `game_history.language`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Tr => "tr",
+            Self::En => "en",
+        }
+    }
+
+    /// Parse a stored value (unknown values fall back to Turkish)
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "en" => Self::En,
+            _ => Self::Tr,
+        }
+    }
+
+    /// Alphabet a new category of this language gets by default
+    pub const fn default_alphabet(self) -> Alphabet {
+        match self {
+            Self::Tr => Alphabet::Turkish,
+            Self::En => Alphabet::English,
+        }
+    }
+}
+
+/// Alphabet whitelist of a category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Alphabet {
+    /// Turkish alphabet (29 letters, plus Â Î Û)
+    #[default]
+    Turkish,
+    /// English alphabet (A-Z)
+    English,
+    /// Letters listed in the category's `custom_letters`
+    Custom,
+}
+
+impl Alphabet {
+    /// Value stored in `categories.alphabet`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Turkish => "turkish",
+            Self::English => "english",
+            Self::Custom => "custom",
+        }
+    }
+
+    /// Parse a stored value (unknown values fall back to Turkish)
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "english" => Self::English,
+            "custom" => Self::Custom,
+            _ => Self::Turkish,
+        }
+    }
 }
 
 /// Word model
@@ -25,6 +111,46 @@ pub struct Word {
     pub created_at: String,
 }
 
+/// Duplicate word merged into another word by the unique-words migration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordMerge {
+    pub id: i32,
+    pub category_id: i32,
+    /// Word that was kept (and may have taken over the removed word's hint)
+    pub kept_word_id: i32,
+    pub removed_word_id: i32,
+    /// Text and hint of the removed word
+    pub word: String,
+    pub hint: String,
+    pub merged_at: String,
+}
+
+/// Results of a word aggregated over all games it was played in
+///
+/// Rates and the difficulty are `None` for words that were never played.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordStatistics {
+    pub word_id: i32,
+    pub category_id: i32,
+    pub word: String,
+    pub hint: String,
+    pub letter_count: i32,
+    pub times_played: i32,
+    pub times_found: i32,
+    pub times_wrong: i32,
+    pub times_skipped: i32,
+    pub times_timeout: i32,
+    /// Plays in which the word had its current hint
+    pub plays_with_current_hint: i32,
+    pub found_rate: Option<f64>,
+    pub timeout_rate: Option<f64>,
+    pub avg_letters_revealed: Option<f64>,
+    pub avg_points: Option<f64>,
+    /// 0 (easiest) to 100 (hardest)
+    pub difficulty: Option<f64>,
+    pub last_played_at: Option<String>,
+}
+
 /// Category validation result
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValidationResult {
@@ -53,7 +179,35 @@ pub struct GameHistory {
     pub game_mode: String,
     pub played_at: String,
     pub total_time_seconds: Option<i32>,
+    /// Scoring preset the game was played with (None for older games)
+    pub scoring_preset_id: Option<i32>,
+    /// Language of the category at the time the game was played
+    pub language: Language,
     pub created_at: String,
+    /// How the words were drawn (None for games saved before seeds were recorded)
+    pub word_draw: Option<WordDraw>,
+    /// Word set the game was played with, None if the words were drawn
+    pub word_set_id: Option<i32>,
+    /// Name of the word set at the time the game was played
+    pub word_set_name: Option<String>,
+}
+
+/// Filters of `get_all_game_history`, every field is optional
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistoryFilter {
+    pub category_id: Option<i32>,
+    pub language: Option<Language>,
+    pub game_mode: Option<String>,
+    /// First day of the range (YYYY-MM-DD)
+    pub start_date: Option<String>,
+    /// Last day of the range (YYYY-MM-DD)
+    pub end_date: Option<String>,
+    /// "date_desc" (default), "date_asc" or "score_desc"
+    pub sort_by: Option<String>,
+    /// Maximum number of entries (default: 50)
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
 }
 
 /// Game participant/team
@@ -80,10 +234,296 @@ pub struct GameWordResult {
     pub participant_id: i32,
     pub word: String,
     pub word_hint: Option<String>,
-    pub result: String, // 'found', 'skipped', or 'timeout'
+    pub result: String, // 'found', 'wrong', 'skipped', 'timeout' or 'unplayed'
     pub points_earned: i32,
     pub letters_used: i32,
     pub created_at: String,
+    /// Played word, `None` for words deleted since the game
+    pub word_id: Option<i32>,
+}
+
+/// Scoring rules applied by the game engine
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScoringRules {
+    /// Points per unrevealed letter
+    pub points_per_letter: i32,
+    /// Share of the word value subtracted on a wrong guess (0 = no penalty)
+    pub wrong_penalty_factor: f64,
+    /// Share of the word value subtracted when the guess timer runs out
+    pub timeout_penalty_factor: f64,
+    /// Extra points for a correct guess without revealing any letter
+    pub no_reveal_bonus: i32,
+    /// Points per unused second when a participant finishes all words
+    pub time_bonus_per_second: i32,
+}
+
+impl Default for ScoringRules {
+    /// The standard rules: 100 points per letter, full value as penalty
+    fn default() -> Self {
+        Self {
+            points_per_letter: 100,
+            wrong_penalty_factor: 1.0,
+            timeout_penalty_factor: 1.0,
+            no_reveal_bonus: 0,
+            time_bonus_per_second: 0,
+        }
+    }
+}
+
+/// Named scoring rules stored in the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringPreset {
+    pub id: i32,
+    pub name: String,
+    #[serde(flatten)]
+    pub rules: ScoringRules,
+    /// Built-in presets cannot be edited or deleted
+    pub is_default: bool,
+    pub created_at: String,
+}
+
+/// Order in which a participant's words are played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WordOrder {
+    /// Shortest words first ("Kelimeler artan zorlukta ilerler")
+    Ascending,
+    /// Shuffled
+    Random,
+}
+
+impl WordOrder {
+    /// Value stored in `game_formats.word_order`
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Random => "random",
+        }
+    }
+
+    /// Parse a stored value (unknown values fall back to ascending)
+    pub fn from_db(value: &str) -> Self {
+        match value {
+            "random" => Self::Random,
+            _ => Self::Ascending,
+        }
+    }
+}
+
+/// Word-length ladder of a game: which lengths are played and how many words each
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameFormat {
+    pub min_length: i32,
+    pub max_length: i32,
+    /// Upper limit of words of the same length per participant
+    pub words_per_length: i32,
+    /// Words per participant, spread over the lengths (shorter lengths first)
+    pub total_words: i32,
+    pub order: WordOrder,
+}
+
+impl Default for GameFormat {
+    /// The standard format: 4-10 letters, 2 words per length, 14 words
+    fn default() -> Self {
+        Self {
+            min_length: 4,
+            max_length: 10,
+            words_per_length: 2,
+            total_words: 14,
+            order: WordOrder::Ascending,
+        }
+    }
+}
+
+/// Named game format stored in the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameFormatPreset {
+    pub id: i32,
+    pub name: String,
+    #[serde(flatten)]
+    pub format: GameFormat,
+    /// Built-in formats cannot be edited or deleted
+    pub is_default: bool,
+    pub created_at: String,
+}
+
+/// How word selection treats recently played words
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecencyStrategy {
+    /// Leave them out, falling back to the least recently played when short
+    #[default]
+    Exclude,
+    /// Keep them, but make them less likely the more often they were played
+    Downweight,
+}
+
+/// Words played in this window of past games of the category are avoided
+///
+/// A game is in the window if it is one of the last `games` games or was
+/// played in the last `days` days.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RecentWordsFilter {
+    pub games: Option<i32>,
+    pub days: Option<i32>,
+    #[serde(default)]
+    pub strategy: RecencyStrategy,
+}
+
+/// Difficulty aimed at by word selection (scores from `get_word_statistics`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DifficultyTarget {
+    /// Scores below 40
+    Easy,
+    /// Scores from 40 to 60 (includes words never played)
+    Medium,
+    /// Scores above 60
+    Hard,
+    /// Easy for the first third of the letter lengths, hard for the last
+    Ramp,
+}
+
+/// What word selection aims for besides the lengths of the format
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct SelectionPreferences {
+    pub avoid_recent: Option<RecentWordsFilter>,
+    pub difficulty: Option<DifficultyTarget>,
+}
+
+/// Options of `select_words`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WordSelectionOptions {
+    /// Words already selected (for other participants)
+    #[serde(default)]
+    pub exclude_ids: Vec<i32>,
+    /// Game format, defaults to the standard format
+    pub format_id: Option<i32>,
+    #[serde(flatten)]
+    pub preferences: SelectionPreferences,
+    /// Seed of the random draw, a new one if omitted
+    pub seed: Option<u64>,
+}
+
+/// Everything needed to draw the words of a game again
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WordDraw {
+    pub seed: u64,
+    /// Game format the words were drawn with (`None` in games saved before
+    /// the default format was recorded, those cannot be drawn again)
+    pub game_format_id: Option<i32>,
+    #[serde(flatten)]
+    pub preferences: SelectionPreferences,
+}
+
+/// Selection constraint that could not be met for a letter length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionConstraint {
+    AvoidRecent,
+    Difficulty,
+}
+
+/// Constraint dropped for one letter length because too few words met it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelaxedConstraint {
+    pub constraint: SelectionConstraint,
+    pub letter_count: i32,
+    /// Words selected although they break the constraint
+    pub words_affected: i32,
+    pub message: String,
+}
+
+/// Words selected for every participant of a game
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordAllocation {
+    /// `words[i]` are the words of the i-th participant
+    pub words: Vec<Vec<Word>>,
+    pub relaxed: Vec<RelaxedConstraint>,
+    /// Seed that draws the same words again
+    pub seed: u64,
+}
+
+/// Words selected for one participant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordSelection {
+    pub words: Vec<Word>,
+    pub relaxed: Vec<RelaxedConstraint>,
+    /// Seed that draws the same words again
+    pub seed: u64,
+}
+
+/// Curated, ordered words played instead of a random draw ("question pack")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordSet {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    /// Format the set is played with, `None` for the standard format
+    pub game_format_id: Option<i32>,
+    /// Words in playing order; each `total_words` of the format go to one participant
+    pub words: Vec<Word>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Whether a word set can be played with a game format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WordSetValidation {
+    pub is_valid: bool,
+    pub word_count: i32,
+    /// Participants the set has words for
+    pub max_participants: i32,
+    /// Everything that keeps the set from being played
+    pub issues: Vec<String>,
+    pub message: String,
+}
+
+/// Database profile ("classroom") with its own database file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileInfo {
+    pub id: String,
+    pub name: String,
+    pub db_path: String,
+    pub is_default: bool,
+    pub is_active: bool,
+}
+
+/// Location of the database currently in use
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseLocation {
+    pub path: String,
+    /// True when the path was set with `--db-path` / `BIL_BAKALIM_DB_PATH`
+    pub is_override: bool,
+    /// Active profile ID (None when the path is overridden)
+    pub profile_id: Option<String>,
+}
+
+/// Row count of a single table in a backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableRowCount {
+    pub table: String,
+    pub rows: i64,
+}
+
+/// Description of a database backup, stored next to the backup file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Application version that wrote the backup (None if unknown)
+    pub app_version: Option<String>,
+    pub schema_version: i32,
+    pub created_at: String,
+    pub size_bytes: u64,
+    pub row_counts: Vec<TableRowCount>,
+}
+
+/// Automatic backup in the backups directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupEntry {
+    pub file_name: String,
+    pub path: String,
+    pub created_at: String,
+    pub size_bytes: u64,
 }
 
 /// Settings map (key-value pairs)
@@ -97,6 +537,13 @@ pub struct CategoryExportInfo {
     pub name: String,
     pub emoji: String,
     pub description: Option<String>,
+    /// Missing in older exports, inferred from the words on import
+    #[serde(default)]
+    pub language: Option<Language>,
+    #[serde(default)]
+    pub alphabet: Option<Alphabet>,
+    #[serde(default)]
+    pub custom_letters: Option<String>,
 }
 
 /// Word information for export (without DB-specific fields)
@@ -117,7 +564,192 @@ pub struct CategoryExportData {
 /// Import result statistics
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ImportResult {
+    /// Category the words were imported into
+    pub category_id: i32,
+    pub category_name: String,
+    /// Whether the category was created by the import
+    pub category_created: bool,
+    pub words_added: i32,
+    pub words_skipped: i32,
+    /// Existing words whose hint was replaced (`MergeStrategy::OverwriteHints`)
+    pub hints_updated: i32,
+    pub message: String,
+    /// Why each skipped row was skipped
+    pub errors: Vec<ImportRowError>,
+}
+
+/// A row of an imported file that was not added
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRowError {
+    /// Line (CSV/TSV/text), row (spreadsheet) or position (JSON) in the file, 1-based
+    pub line: usize,
+    /// Word as written in the file
+    pub word: String,
+    pub reason: String,
+}
+
+/// Outcome of one incoming word of an import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportWordStatus {
+    Accepted,
+    /// Already in the category or earlier in the file
+    Duplicate,
+    /// Letters outside the category's alphabet
+    InvalidCharacters,
+    /// Letter count outside the supported range
+    BadLength,
+    /// Letter count stated in the file differs from the word
+    LetterCountMismatch,
+    /// Duplicate whose hint replaced the existing word's hint
+    HintUpdated,
+    /// Row without a word
+    MissingWord,
+}
+
+/// Diagnostics for one incoming word of an import preview
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportPreviewWord {
+    /// Line (CSV/TSV/text), row (spreadsheet) or position (JSON) in the file, 1-based
+    pub line: usize,
+    /// Word as written in the file
+    pub word: String,
+    /// Form the word is stored in (normalized and upper-cased), when it could be read
+    pub normalized: Option<String>,
+    pub letter_count: Option<i32>,
+    pub hint: String,
+    pub status: ImportWordStatus,
+    /// Why the word is not accepted
+    pub reason: Option<String>,
+    /// ID of the existing word a duplicate matches (None for repeats within the file)
+    pub duplicate_of: Option<i32>,
+}
+
+/// Result of an import dry run, nothing is written
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportPreview {
+    /// Target category (0 when it would be created)
+    pub category_id: i32,
+    pub category_name: String,
+    /// Whether the import would create the category
+    pub category_created: bool,
+    pub words_accepted: i32,
+    /// Words not added (hint updates are neither accepted nor rejected)
+    pub words_rejected: i32,
+    pub words: Vec<ImportPreviewWord>,
+    /// Validation of the category as it would be after the import
+    pub validation: ValidationResult,
+}
+
+/// How an import handles what already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Import into the category of the same name, skip duplicate words
+    #[default]
+    Skip,
+    /// Like `Skip`, but duplicate words take the incoming hint
+    OverwriteHints,
+    /// Create a new category ("Name (2)") when the name is taken
+    Rename,
+}
+
+/// Contents of an export file
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExportPayload {
+    /// One category with its words (`export_category_json`)
+    Category(CategoryExportData),
+    /// Every category of the library (`export_library`)
+    Library { categories: Vec<CategoryExportData> },
+}
+
+/// Export file: the payload plus what is needed to read it back safely
+///
+/// See `crate::export` for how files of older versions are upgraded.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportFile {
+    /// Layout version, see `export::FORMAT_VERSION`
+    pub format_version: u32,
+    /// Version of the app that wrote the file (unknown for upgraded files)
+    pub app_version: Option<String>,
+    pub exported_at: Option<String>,
+    pub author: Option<String>,
+    /// Language of the words, `None` when the categories differ
+    pub language: Option<Language>,
+    pub word_count: i32,
+    /// SHA-256 of the payload, hex encoded (see `export::payload_checksum`)
+    pub sha256: String,
+    pub payload: ExportPayload,
+}
+
+/// File formats written by `export_category_to_file`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Pretty-printed `ExportFile`
+    #[default]
+    Json,
+    /// "Kelime;İpucu" columns, readable by `import_words`
+    Csv,
+}
+
+/// Output formats of the report commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    /// Standalone HTML page
+    Html,
+    Pdf,
+}
+
+/// Result of importing a library bundle
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LibraryImportResult {
+    pub categories_created: i32,
+    /// Categories whose words were merged into an existing category
+    pub categories_merged: i32,
     pub words_added: i32,
     pub words_skipped: i32,
+    pub hints_updated: i32,
+    /// Result per category of the bundle, in bundle order
+    pub categories: Vec<ImportResult>,
     pub message: String,
 }
+
+/// File formats accepted by `import_words`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Csv,
+    Tsv,
+    /// Excel workbook (.xlsx, .xls) or OpenDocument spreadsheet (.ods)
+    Xlsx,
+    /// One "WORD - hint" per line
+    Text,
+    /// Category export file (`ExportFile`, or a bare `CategoryExportData`)
+    Json,
+}
+
+/// Column of a CSV/TSV/spreadsheet file: header name or 1-based position
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ColumnRef {
+    Position(usize),
+    Name(String),
+}
+
+/// Options of `import_words`, every field is detected when omitted
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImportOptions {
+    pub format: Option<ImportFormat>,
+    /// Column holding the word (default: "Kelime"/"Word" header, else the first column)
+    pub word_column: Option<ColumnRef>,
+    /// Column holding the hint (default: "İpucu"/"Hint" header, else the second column)
+    pub hint_column: Option<ColumnRef>,
+    /// Whether the first row is a header (default: detected from the column names)
+    pub has_header: Option<bool>,
+    /// Worksheet to read (default: the first one)
+    pub sheet: Option<String>,
+}
@@ -0,0 +1,130 @@
+//! Standalone HTML rendering
+//!
+//! One file with inline styles and no scripts or external resources, laid
+//! out for printing on A4.
+
+use super::Report;
+use std::fmt::Write;
+
+const STYLE: &str = "
+@page { size: A4; margin: 18mm; }
+body { font-family: 'Segoe UI', Helvetica, Arial, sans-serif; color: #1f2933; font-size: 11pt; margin: 24px; }
+h1 { font-size: 20pt; margin: 0 0 8px; }
+h2 { font-size: 13pt; margin: 24px 0 8px; }
+dl { display: grid; grid-template-columns: max-content 1fr; gap: 2px 12px; margin: 0; }
+dt { color: #616e7c; }
+dd { margin: 0; }
+table { width: 100%; border-collapse: collapse; font-size: 10pt; }
+th, td { border: 1px solid #cbd2d9; padding: 4px 6px; text-align: left; vertical-align: top; }
+th { background: #f0f2f5; }
+td.num, th.num { text-align: right; white-space: nowrap; }
+tr { page-break-inside: avoid; }
+thead { display: table-header-group; }
+footer { margin-top: 24px; color: #9aa5b1; font-size: 9pt; }
+@media print { body { margin: 0; } }
+";
+
+/// Render a report as a complete HTML document
+pub fn render(report: &Report) -> String {
+    let mut html = String::new();
+
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"tr\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>{}</style>\n</head>\n<body>\n",
+        escape(&report.title),
+        STYLE
+    );
+
+    let icon = report
+        .icon
+        .as_deref()
+        .map(|icon| format!("{} ", escape(icon)))
+        .unwrap_or_default();
+    let _ = writeln!(html, "<h1>{}{}</h1>", icon, escape(&report.title));
+
+    if !report.details.is_empty() {
+        html.push_str("<dl>\n");
+        for (label, value) in &report.details {
+            let _ = writeln!(html, "<dt>{}</dt><dd>{}</dd>", escape(label), escape(value));
+        }
+        html.push_str("</dl>\n");
+    }
+
+    for section in &report.sections {
+        let total: u16 = section.columns.iter().map(|column| column.weight).sum();
+        let class = |numeric: bool| if numeric { " class=\"num\"" } else { "" };
+
+        let _ = writeln!(html, "<h2>{}</h2>", escape(&section.heading));
+        html.push_str("<table>\n<thead><tr>");
+        for column in &section.columns {
+            let _ = write!(
+                html,
+                "<th{} style=\"width: {:.1}%\">{}</th>",
+                class(column.numeric),
+                f32::from(column.weight) * 100.0 / f32::from(total.max(1)),
+                escape(&column.title)
+            );
+        }
+        html.push_str("</tr></thead>\n<tbody>\n");
+
+        for row in &section.rows {
+            html.push_str("<tr>");
+            for (column, cell) in section.columns.iter().zip(row) {
+                let _ = write!(html, "<td{}>{}</td>", class(column.numeric), escape(cell));
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n</table>\n");
+    }
+
+    let _ = write!(
+        html,
+        "<footer>Bil Bakalım ile oluşturuldu: {}</footer>\n</body>\n</html>\n",
+        escape(&report.generated_at)
+    );
+
+    html
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{Column, Section};
+
+    #[test]
+    fn text_is_escaped_and_tables_are_rendered() {
+        let report = Report {
+            title: "Kısa <b>liste</b>".to_string(),
+            icon: Some("🐾".to_string()),
+            details: vec![("Dil".to_string(), "Türkçe".to_string())],
+            sections: vec![Section {
+                heading: "Kelimeler".to_string(),
+                columns: vec![Column::number("#", 1), Column::text("Kelime", 3)],
+                rows: vec![vec!["1".to_string(), "AĞAÇ & DAL".to_string()]],
+            }],
+            generated_at: "2026-01-01 10:00".to_string(),
+        };
+        let html = render(&report);
+
+        assert!(html.contains("<h1>🐾 Kısa &lt;b&gt;liste&lt;/b&gt;</h1>"));
+        assert!(html.contains("<td class=\"num\">1</td><td>AĞAÇ &amp; DAL</td>"));
+        assert!(html.contains("width: 75.0%"));
+        assert!(!html.contains("<script"));
+    }
+}
@@ -0,0 +1,332 @@
+//! Printable reports
+//!
+//! A category word list or a finished game is first described as a
+//! [`Report`] (title, details and tables), which is then rendered as a
+//! standalone HTML page or a PDF. Both renderers are self-contained, so
+//! reports work without network access.
+//!
+//! Modules:
+//! - `html`: single-file HTML with inline styles
+//! - `pdf`: PDF using the standard Helvetica fonts
+
+pub mod html;
+pub mod pdf;
+
+use crate::models::{
+    Category, CategoryExportData, GameHistory, GameParticipant, GameWordResult, Language,
+};
+
+/// Document to render
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub title: String,
+    /// Shown before the title in HTML (category emoji); PDFs leave it out
+    pub icon: Option<String>,
+    /// Label and value pairs under the title
+    pub details: Vec<(String, String)>,
+    pub sections: Vec<Section>,
+    pub generated_at: String,
+}
+
+/// Titled table of a report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub heading: String,
+    pub columns: Vec<Column>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Table column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub title: String,
+    /// Share of the table width relative to the other columns
+    pub weight: u16,
+    /// Right-aligned
+    pub numeric: bool,
+}
+
+impl Column {
+    fn text(title: &str, weight: u16) -> Self {
+        Self {
+            title: title.to_string(),
+            weight,
+            numeric: false,
+        }
+    }
+
+    fn number(title: &str, weight: u16) -> Self {
+        Self {
+            title: title.to_string(),
+            weight,
+            numeric: true,
+        }
+    }
+}
+
+/// Word list of a category with its distribution by length
+///
+/// `export` holds the words sorted by length, then alphabetically.
+pub fn category_report(
+    category: &Category,
+    export: &CategoryExportData,
+    generated_at: String,
+) -> Report {
+    let mut details = Vec::new();
+    if let Some(description) = category
+        .description
+        .as_deref()
+        .filter(|description| !description.trim().is_empty())
+    {
+        details.push(("Açıklama".to_string(), description.to_string()));
+    }
+    details.push((
+        "Dil".to_string(),
+        language_name(category.language).to_string(),
+    ));
+    details.push(("Kelime sayısı".to_string(), export.words.len().to_string()));
+
+    let mut distribution: Vec<(i32, usize)> = Vec::new();
+    for word in &export.words {
+        match distribution
+            .iter_mut()
+            .find(|(length, _)| *length == word.letter_count)
+        {
+            Some((_, count)) => *count += 1,
+            None => distribution.push((word.letter_count, 1)),
+        }
+    }
+    distribution.sort_unstable();
+
+    let distribution = Section {
+        heading: "Harf Sayısına Göre Dağılım".to_string(),
+        columns: vec![Column::number("Harf", 1), Column::number("Kelime", 1)],
+        rows: distribution
+            .into_iter()
+            .map(|(length, count)| vec![length.to_string(), count.to_string()])
+            .collect(),
+    };
+
+    let words = Section {
+        heading: "Kelimeler".to_string(),
+        columns: vec![
+            Column::number("#", 1),
+            Column::text("Kelime", 4),
+            Column::number("Harf", 1),
+            Column::text("İpucu", 10),
+        ],
+        rows: export
+            .words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                vec![
+                    (index + 1).to_string(),
+                    word.word.clone(),
+                    word.letter_count.to_string(),
+                    word.hint.clone(),
+                ]
+            })
+            .collect(),
+    };
+
+    Report {
+        title: category.name.clone(),
+        icon: Some(category.emoji.clone()).filter(|emoji| !emoji.is_empty()),
+        details,
+        sections: vec![distribution, words],
+        generated_at,
+    }
+}
+
+/// Results of a finished game: ranking, then the words of every participant
+///
+/// `participants` are in rank order, each with its word results in play order.
+pub fn game_report(
+    history: &GameHistory,
+    participants: &[(GameParticipant, Vec<GameWordResult>)],
+    generated_at: String,
+) -> Report {
+    let mut details = vec![
+        ("Kategori".to_string(), history.category_name.clone()),
+        (
+            "Oyun modu".to_string(),
+            mode_name(&history.game_mode).to_string(),
+        ),
+        (
+            "Oynanma tarihi".to_string(),
+            display_time(&history.played_at),
+        ),
+    ];
+    if let Some(seconds) = history.total_time_seconds {
+        details.push(("Toplam süre".to_string(), duration(seconds)));
+    }
+
+    let ranking = Section {
+        heading: "Sıralama".to_string(),
+        columns: vec![
+            Column::number("Sıra", 2),
+            Column::text("Ad", 6),
+            Column::number("Puan", 2),
+            Column::number("Bilinen", 2),
+            Column::number("Pas", 2),
+            Column::number("Açılan harf", 3),
+            Column::number("Süre", 2),
+        ],
+        rows: participants
+            .iter()
+            .map(|(participant, _)| {
+                vec![
+                    participant
+                        .rank
+                        .map_or_else(|| "-".to_string(), |rank| rank.to_string()),
+                    participant.participant_name.clone(),
+                    participant.score.to_string(),
+                    participant.words_found.to_string(),
+                    participant.words_skipped.to_string(),
+                    participant.letters_revealed.to_string(),
+                    participant
+                        .elapsed_time_seconds
+                        .map_or_else(|| "-".to_string(), duration),
+                ]
+            })
+            .collect(),
+    };
+
+    let mut sections = vec![ranking];
+    for (participant, results) in participants {
+        sections.push(Section {
+            heading: format!("{} - Kelimeler", participant.participant_name),
+            columns: vec![
+                Column::number("#", 1),
+                Column::text("Kelime", 4),
+                Column::text("İpucu", 8),
+                Column::text("Sonuç", 3),
+                Column::number("Açılan harf", 3),
+                Column::number("Puan", 2),
+            ],
+            rows: results
+                .iter()
+                .enumerate()
+                .map(|(index, result)| {
+                    vec![
+                        (index + 1).to_string(),
+                        result.word.clone(),
+                        result.word_hint.clone().unwrap_or_default(),
+                        result_name(&result.result).to_string(),
+                        result.letters_used.to_string(),
+                        result.points_earned.to_string(),
+                    ]
+                })
+                .collect(),
+        });
+    }
+
+    Report {
+        title: format!("Oyun Raporu #{}", history.id),
+        icon: None,
+        details,
+        sections,
+        generated_at,
+    }
+}
+
+const fn language_name(language: Language) -> &'static str {
+    match language {
+        Language::Tr => "Türkçe",
+        Language::En => "İngilizce",
+    }
+}
+
+fn mode_name(mode: &str) -> &str {
+    match mode {
+        "single" => "Tek oyuncu",
+        "multi" => "Çoklu oyuncu",
+        "team" => "Takım",
+        other => other,
+    }
+}
+
+fn result_name(result: &str) -> &str {
+    match result {
+        "found" => "Bildi",
+        "wrong" => "Yanlış",
+        "skipped" => "Pas",
+        "timeout" => "Süre doldu",
+        "unplayed" => "Oynanmadı",
+        other => other,
+    }
+}
+
+/// Timestamp as `YYYY-MM-DD HH:MM` (stored as either `datetime('now')` or ISO 8601 text)
+fn display_time(timestamp: &str) -> String {
+    timestamp.replacen('T', " ", 1).chars().take(16).collect()
+}
+
+/// Seconds as "m:ss"
+fn duration(seconds: i32) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Alphabet, CategoryExportInfo, WordExportInfo};
+
+    fn category() -> Category {
+        Category {
+            id: 1,
+            name: "Hayvanlar".to_string(),
+            emoji: "🐾".to_string(),
+            description: None,
+            is_default: false,
+            created_at: String::new(),
+            updated_at: String::new(),
+            language: Language::Tr,
+            alphabet: Alphabet::Turkish,
+            custom_letters: None,
+        }
+    }
+
+    fn export(words: &[(&str, i32)]) -> CategoryExportData {
+        CategoryExportData {
+            category: CategoryExportInfo {
+                name: "Hayvanlar".to_string(),
+                emoji: "🐾".to_string(),
+                description: None,
+                language: None,
+                alphabet: None,
+                custom_letters: None,
+            },
+            words: words
+                .iter()
+                .map(|&(word, letter_count)| WordExportInfo {
+                    word: word.to_string(),
+                    letter_count,
+                    hint: String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn category_report_counts_words_by_length() {
+        let export = export(&[("FİL", 3), ("AYI", 3), ("ASLAN", 5)]);
+        let report = category_report(&category(), &export, "2026-01-01 10:00".to_string());
+
+        assert_eq!(report.icon.as_deref(), Some("🐾"));
+        assert_eq!(
+            report.sections[0].rows,
+            vec![vec!["3", "2"], vec!["5", "1"]]
+        );
+        assert_eq!(report.sections[1].rows[2][..3], ["3", "ASLAN", "5"]);
+    }
+
+    #[test]
+    fn times_are_shown_to_the_minute() {
+        assert_eq!(duration(65), "1:05");
+        assert_eq!(duration(600), "10:00");
+        assert_eq!(display_time("2026-10-17T15:10:51.471Z"), "2026-10-17 15:10");
+        assert_eq!(display_time("2026-10-17 15:10:51"), "2026-10-17 15:10");
+    }
+}
@@ -0,0 +1,576 @@
+//! PDF rendering
+//!
+//! Writes the PDF by hand with the standard Helvetica fonts, so no font
+//! file has to be embedded or found on the system. Text is encoded as
+//! Windows-1254 and the fonts are given a `WinAnsiEncoding` whose free slots
+//! are remapped to the Turkish letters (Ğ ğ İ ı Ş ş) at their Windows-1254
+//! positions. Characters outside Windows-1254 (emoji) are printed as "?".
+
+use super::{Report, Section};
+use std::fmt::Write;
+use unicode_normalization::UnicodeNormalization;
+
+/// A4 in points
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+/// Baseline of the page footer
+const FOOTER_Y: f32 = 30.0;
+
+const TITLE_SIZE: f32 = 18.0;
+const HEADING_SIZE: f32 = 13.0;
+const DETAIL_SIZE: f32 = 10.0;
+const TABLE_SIZE: f32 = 9.0;
+const FOOTER_SIZE: f32 = 8.0;
+/// Line height of table text
+const TABLE_LINE: f32 = 11.0;
+const CELL_PADDING: f32 = 4.0;
+
+/// Font encoding: `WinAnsiEncoding` with the Turkish letters at their Windows-1254 codes
+const ENCODING: &str = "<< /Type /Encoding /BaseEncoding /WinAnsiEncoding \
+     /Differences [208 /Gbreve 221 /Idotaccent /Scedilla 240 /gbreve 253 /dotlessi /scedilla] >>";
+
+/// Helvetica advance widths for ' ' to '~' (1/1000 em)
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278,
+    278, // ' ' to '/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // digits
+    278, 278, 584, 584, 584, 556, 1015, // ':' to '@'
+    667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667,
+    611, 722, 667, 944, 667, 667, 611, // 'A' to 'Z'
+    278, 278, 278, 469, 556, 333, // '[' to '`'
+    556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, 556, 556, 333, 500,
+    278, 556, 500, 722, 500, 500, 500, // 'a' to 'z'
+    334, 260, 334, 584, // '{' to '~'
+];
+
+/// Helvetica-Bold advance widths for ' ' to '~' (1/1000 em)
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278,
+    278, // ' ' to '/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // digits
+    333, 333, 584, 584, 584, 611, 975, // ':' to '@'
+    722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667,
+    611, 722, 667, 944, 667, 667, 611, // 'A' to 'Z'
+    333, 278, 333, 584, 556, 333, // '[' to '`'
+    556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611, 611, 611, 389, 556,
+    333, 611, 556, 778, 556, 556, 500, // 'a' to 'z'
+    389, 280, 389, 584, // '{' to '~'
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    const fn resource(self) -> &'static str {
+        match self {
+            Self::Regular => "F1",
+            Self::Bold => "F2",
+        }
+    }
+
+    const fn widths(self) -> &'static [u16; 95] {
+        match self {
+            Self::Regular => &HELVETICA_WIDTHS,
+            Self::Bold => &HELVETICA_BOLD_WIDTHS,
+        }
+    }
+}
+
+/// Render a report as a PDF file
+pub fn render(report: &Report) -> Vec<u8> {
+    let mut layout = Layout::new();
+
+    layout.text(Font::Bold, TITLE_SIZE, MARGIN, &report.title);
+    layout.y -= TITLE_SIZE + 8.0;
+
+    for (label, value) in &report.details {
+        let label = format!("{}: ", label);
+        let indent = text_width(&label, Font::Bold, DETAIL_SIZE);
+        let lines = wrap(
+            value,
+            Font::Regular,
+            DETAIL_SIZE,
+            PAGE_WIDTH - 2.0 * MARGIN - indent,
+        );
+
+        // The label shares its line with the first line of the value, so
+        // both move to a new page together
+        layout.ensure_space(DETAIL_SIZE + 4.0);
+        layout.text(Font::Bold, DETAIL_SIZE, MARGIN, &label);
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                layout.ensure_space(DETAIL_SIZE + 4.0);
+            }
+            layout.text(Font::Regular, DETAIL_SIZE, MARGIN + indent, line);
+            layout.y -= DETAIL_SIZE + 4.0;
+        }
+    }
+
+    for section in &report.sections {
+        layout.section(section);
+    }
+
+    layout.finish(report)
+}
+
+/// Pages being filled top to bottom
+struct Layout {
+    /// Content stream of every page
+    pages: Vec<String>,
+    /// Baseline of the next line on the current page
+    y: f32,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self {
+            pages: vec![String::new()],
+            y: PAGE_HEIGHT - MARGIN - TITLE_SIZE,
+        }
+    }
+
+    fn page(&mut self) -> &mut String {
+        self.pages.last_mut().expect("layout has a page")
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(String::new());
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    /// Start a new page unless `height` fits above the bottom margin
+    fn ensure_space(&mut self, height: f32) -> bool {
+        if self.y - height < MARGIN {
+            self.new_page();
+            return true;
+        }
+        false
+    }
+
+    fn text(&mut self, font: Font, size: f32, x: f32, text: &str) {
+        let y = self.y;
+        draw_text(self.page(), font, size, x, y, text);
+    }
+
+    fn section(&mut self, section: &Section) {
+        let total: u16 = section.columns.iter().map(|column| column.weight).sum();
+        let table_width = PAGE_WIDTH - 2.0 * MARGIN;
+        let widths: Vec<f32> = section
+            .columns
+            .iter()
+            .map(|column| table_width * f32::from(column.weight) / f32::from(total.max(1)))
+            .collect();
+
+        // Keep the heading together with the table header and a first row
+        self.y -= 10.0;
+        self.ensure_space(HEADING_SIZE + 6.0 + 3.0 * (TABLE_LINE + 2.0 * CELL_PADDING));
+        self.text(Font::Bold, HEADING_SIZE, MARGIN, &section.heading);
+        self.y -= HEADING_SIZE;
+
+        let header: Vec<String> = section
+            .columns
+            .iter()
+            .map(|column| column.title.clone())
+            .collect();
+        self.row(section, &widths, &header, Font::Bold);
+
+        for row in &section.rows {
+            let height = row_height(&widths, row, Font::Regular);
+            if self.ensure_space(height) {
+                self.row(section, &widths, &header, Font::Bold);
+            }
+            self.row(section, &widths, row, Font::Regular);
+        }
+    }
+
+    /// Draw a table row below the current position
+    fn row(&mut self, section: &Section, widths: &[f32], cells: &[String], font: Font) {
+        let height = row_height(widths, cells, font);
+        let top = self.y;
+        let bottom = top - height;
+
+        if font == Font::Bold {
+            let page = self.page();
+            let _ = writeln!(
+                page,
+                "0.94 g {:.2} {:.2} {:.2} {:.2} re f 0 g",
+                MARGIN,
+                bottom,
+                PAGE_WIDTH - 2.0 * MARGIN,
+                height
+            );
+        }
+
+        let mut x = MARGIN;
+        for ((column, width), cell) in section.columns.iter().zip(widths).zip(cells) {
+            let lines = wrap(cell, font, TABLE_SIZE, width - 2.0 * CELL_PADDING);
+            for (index, line) in lines.iter().enumerate() {
+                let line_x = if column.numeric {
+                    x + width - CELL_PADDING - text_width(line, font, TABLE_SIZE)
+                } else {
+                    x + CELL_PADDING
+                };
+                self.y = top - CELL_PADDING - TABLE_SIZE - TABLE_LINE * index as f32;
+                self.text(font, TABLE_SIZE, line_x, line);
+            }
+            x += width;
+        }
+
+        let page = self.page();
+        let _ = writeln!(
+            page,
+            "0.8 G 0.5 w {:.2} {:.2} m {:.2} {:.2} l S 0 G",
+            MARGIN,
+            bottom,
+            PAGE_WIDTH - MARGIN,
+            bottom
+        );
+        self.y = bottom;
+    }
+
+    /// Add the page footers and write the file
+    fn finish(mut self, report: &Report) -> Vec<u8> {
+        let page_count = self.pages.len();
+        let footer = format!("Bil Bakalım · {}", report.generated_at);
+
+        for (index, page) in self.pages.iter_mut().enumerate() {
+            let page_number = format!("Sayfa {} / {}", index + 1, page_count);
+            let number_x =
+                PAGE_WIDTH - MARGIN - text_width(&page_number, Font::Regular, FOOTER_SIZE);
+
+            page.push_str("0.55 g\n");
+            draw_text(page, Font::Regular, FOOTER_SIZE, MARGIN, FOOTER_Y, &footer);
+            draw_text(
+                page,
+                Font::Regular,
+                FOOTER_SIZE,
+                number_x,
+                FOOTER_Y,
+                &page_number,
+            );
+            page.push_str("0 g\n");
+        }
+
+        write_document(&self.pages, &report.title)
+    }
+}
+
+fn draw_text(page: &mut String, font: Font, size: f32, x: f32, y: f32, text: &str) {
+    let _ = write!(
+        page,
+        "BT /{} {} Tf {:.2} {:.2} Td ",
+        font.resource(),
+        size,
+        x,
+        y
+    );
+    write_string(page, &encode(text));
+    page.push_str(" Tj ET\n");
+}
+
+/// Height of a table row with its cells wrapped to the column widths
+fn row_height(widths: &[f32], cells: &[String], font: Font) -> f32 {
+    let lines = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| wrap(cell, font, TABLE_SIZE, width - 2.0 * CELL_PADDING).len())
+        .max()
+        .unwrap_or(1)
+        .max(1);
+
+    TABLE_LINE * lines as f32 + 2.0 * CELL_PADDING - (TABLE_LINE - TABLE_SIZE)
+}
+
+/// Split text into lines no wider than `max_width`
+///
+/// Breaks at spaces; a word longer than a line is broken between letters.
+fn wrap(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", line, word)
+        };
+        if text_width(&candidate, font, size) <= max_width {
+            line = candidate;
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        for c in word.chars() {
+            line.push(c);
+            if line.chars().count() > 1 && text_width(&line, font, size) > max_width {
+                line.pop();
+                lines.push(std::mem::replace(&mut line, c.to_string()));
+            }
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Width of text in points
+fn text_width(text: &str, font: Font, size: f32) -> f32 {
+    let units: u32 = text.nfc().map(|c| u32::from(char_width(c, font))).sum();
+    units as f32 * size / 1000.0
+}
+
+fn char_width(c: char, font: Font) -> u16 {
+    let widths = font.widths();
+    let ascii_width = |c: char| {
+        u32::from(c)
+            .checked_sub(32)
+            .and_then(|index| widths.get(index as usize))
+            .copied()
+    };
+
+    if c == 'ı' {
+        return 278;
+    }
+    // Accented letters are as wide as their base letter
+    ascii_width(c)
+        .or_else(|| c.to_string().nfd().next().and_then(ascii_width))
+        .unwrap_or(556)
+}
+
+/// Text as Windows-1254 bytes, "?" for characters it cannot hold
+fn encode(text: &str) -> Vec<u8> {
+    let mut buffer = [0; 4];
+    text.nfc()
+        .map(|c| match c {
+            ' '..='~' => c as u8,
+            c if c.is_whitespace() => b' ',
+            c => {
+                let (bytes, _, had_errors) =
+                    encoding_rs::WINDOWS_1254.encode(c.encode_utf8(&mut buffer));
+                match *bytes {
+                    [byte] if !had_errors && byte >= 0x80 => byte,
+                    _ => b'?',
+                }
+            }
+        })
+        .collect()
+}
+
+/// PDF literal string, non-ASCII bytes as octal escapes
+fn write_string(out: &mut String, bytes: &[u8]) {
+    out.push('(');
+    for &byte in bytes {
+        match byte {
+            b'(' | b')' | b'\\' => {
+                out.push('\\');
+                out.push(byte as char);
+            }
+            b' '..=b'~' => out.push(byte as char),
+            _ => {
+                let _ = write!(out, "\\{:03o}", byte);
+            }
+        }
+    }
+    out.push(')');
+}
+
+/// PDF text string (document info) as UTF-16BE hex
+fn text_string(text: &str) -> String {
+    text.encode_utf16()
+        .fold(String::from("<FEFF"), |mut hex, unit| {
+            let _ = write!(hex, "{:04X}", unit);
+            hex
+        })
+        + ">"
+}
+
+/// Assemble the PDF objects and cross-reference table
+fn write_document(pages: &[String], title: &str) -> Vec<u8> {
+    // 1: catalog, 2: page tree, 3-4: fonts, 5: info, then a page and its
+    // content stream for every page
+    let page_ids: Vec<usize> = (0..pages.len()).map(|index| 6 + 2 * index).collect();
+
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        format!(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding {} >>",
+            ENCODING
+        ),
+        format!(
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding {} >>",
+            ENCODING
+        ),
+        format!(
+            "<< /Title {} /Producer {} >>",
+            text_string(title),
+            text_string("Bil Bakalım")
+        ),
+    ];
+
+    for (page, id) in pages.iter().zip(&page_ids) {
+        // The end-of-line before `endstream` is not part of the stream
+        let content = page.strip_suffix('\n').unwrap_or(page);
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}\nendstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        let _ = write!(pdf, "{} 0 obj\n{}\nendobj\n", index + 1, object);
+    }
+
+    let xref = pdf.len();
+    let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(pdf, "{:010} 00000 n ", offset);
+    }
+    let _ = write!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    );
+
+    pdf.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::Column;
+
+    fn report(rows: usize) -> Report {
+        Report {
+            title: "Hayvanlar".to_string(),
+            icon: Some("🐾".to_string()),
+            details: vec![("Dil".to_string(), "Türkçe".to_string())],
+            sections: vec![Section {
+                heading: "Kelimeler".to_string(),
+                columns: vec![Column::number("#", 1), Column::text("Kelime", 5)],
+                rows: (1..=rows)
+                    .map(|index| vec![index.to_string(), "ŞAHİN".to_string()])
+                    .collect(),
+            }],
+            generated_at: "2026-01-01 10:00".to_string(),
+        }
+    }
+
+    fn page_count(pdf: &str) -> usize {
+        pdf.matches("/Type /Page ").count()
+    }
+
+    #[test]
+    fn long_tables_continue_on_new_pages() {
+        let pdf = String::from_utf8(render(&report(5))).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert_eq!(page_count(&pdf), 1);
+
+        let pdf = String::from_utf8(render(&report(200))).unwrap();
+        let pages = page_count(&pdf);
+        assert!(pages > 3);
+        assert!(pdf.contains(&format!("(Sayfa {} / {})", pages, pages)));
+        // The table header is repeated on every page
+        assert_eq!(pdf.matches("(Kelime)").count(), pages);
+    }
+
+    #[test]
+    fn cross_reference_offsets_point_at_objects() {
+        let pdf = String::from_utf8(render(&report(3))).unwrap();
+        let xref: usize = pdf
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+        assert!(pdf[xref..].starts_with("xref"));
+
+        for (index, entry) in pdf[xref..].lines().skip(3).take(5).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj", index + 1)));
+        }
+    }
+
+    #[test]
+    fn detail_labels_stay_with_their_values() {
+        let mut report = report(0);
+        report.details = (0..80)
+            .map(|index| (format!("Etiket {}", index), "değer ".repeat(index % 4 * 40)))
+            .collect();
+        let pdf = String::from_utf8(render(&report)).unwrap();
+
+        // A label is always followed by its value on the same page
+        for page in pdf.split("\nstream\n").skip(1) {
+            let page = &page[..page.find("endstream").unwrap()];
+            let lines: Vec<&str> = page.lines().collect();
+            for (index, line) in lines.iter().enumerate() {
+                if line.contains("(Etiket ") {
+                    assert!(lines
+                        .get(index + 1)
+                        .is_some_and(|next| next.starts_with("BT /F1")));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stream_lengths_exclude_the_end_of_line() {
+        let pdf = String::from_utf8(render(&report(3))).unwrap();
+        for stream in pdf.split("/Length ").skip(1) {
+            let (length, rest) = stream.split_once(" >>\nstream\n").unwrap();
+            let end = rest.find("\nendstream").unwrap();
+            assert_eq!(length.parse::<usize>().unwrap(), end);
+        }
+    }
+
+    #[test]
+    fn turkish_letters_use_windows_1254_codes() {
+        assert_eq!(encode("ŞİĞ ışğ"), b"\xDE\xDD\xD0 \xFD\xFE\xF0");
+        assert_eq!(encode("Çöü 🐾"), b"\xC7\xF6\xFC ?");
+
+        let mut out = String::new();
+        write_string(&mut out, &encode("(Ş)"));
+        assert_eq!(out, "(\\(\\336\\))");
+    }
+
+    #[test]
+    fn text_wraps_at_spaces_then_between_letters() {
+        let lines = wrap("bir iki üç", Font::Regular, 10.0, 30.0);
+        assert_eq!(lines, vec!["bir iki", "üç"]);
+
+        let lines = wrap("ÇEKOSLOVAKYALILAŞTIRAMADIKLARIMIZ", Font::Bold, 10.0, 60.0);
+        assert!(lines.len() > 1);
+        assert!(lines
+            .iter()
+            .all(|line| text_width(line, Font::Bold, 10.0) <= 60.0));
+        assert_eq!(wrap("", Font::Regular, 10.0, 40.0), vec![""]);
+    }
+}
@@ -0,0 +1,397 @@
+//! Word text handling
+//!
+//! Every path that writes a word goes through [`prepare_word`]: the text is
+//! NFC-normalized, upper-cased with the category language's rules (Turkish:
+//! i → İ, ı → I), checked against the category's alphabet and measured in
+//! graphemes, so a letter typed as a base letter plus a combining mark still
+//! counts as one letter.
+
+use crate::errors::AppError;
+use crate::game::format::validate_word_length;
+use crate::models::{Alphabet, Category, Language};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Turkish alphabet (29 letters) plus the circumflexed vowels used in Turkish
+pub const TURKISH_LETTERS: &str = "ABCÇDEFGĞHIİJKLMNOÖPRSŞTUÜVYZÂÎÛ";
+
+/// English alphabet
+pub const ENGLISH_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Turkish letters from most to least frequent
+const TURKISH_FREQUENCY: &str = "AEİNRLIKDMYUTSBOÜŞZGÇHĞVCÖPFJ";
+
+/// English letters from most to least frequent
+const ENGLISH_FREQUENCY: &str = "ETAOINSHRDLCUMWFGYPBVKJXQZ";
+
+/// Language and alphabet rules a category applies to its words
+#[derive(Debug, Clone, Copy)]
+pub struct WordRules<'a> {
+    pub language: Language,
+    pub alphabet: Alphabet,
+    /// Allowed letters for [`Alphabet::Custom`]
+    pub custom_letters: Option<&'a str>,
+}
+
+impl<'a> From<&'a Category> for WordRules<'a> {
+    fn from(category: &'a Category) -> Self {
+        Self {
+            language: category.language,
+            alphabet: category.alphabet,
+            custom_letters: category.custom_letters.as_deref(),
+        }
+    }
+}
+
+/// Trim and NFC-normalize text
+pub fn normalize(text: &str) -> String {
+    text.trim().nfc().collect()
+}
+
+/// Upper-case text with the casing rules of the language
+///
+/// Turkish maps i → İ and ı → I, English uses the default Unicode mapping.
+pub fn to_upper(text: &str, language: Language) -> String {
+    if language == Language::En {
+        return text.to_uppercase().nfc().collect();
+    }
+
+    let mut upper = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'i' => upper.push('İ'),
+            'ı' => upper.push('I'),
+            // "i" followed by a combining dot above is already "İ"
+            '\u{307}' if upper.ends_with('İ') => {}
+            _ => upper.extend(c.to_uppercase()),
+        }
+    }
+
+    upper.nfc().collect()
+}
+
+/// Lower-case text with the casing rules of the language
+///
+/// Turkish maps I → ı and İ → i, English uses the default Unicode mapping.
+pub fn to_lower(text: &str, language: Language) -> String {
+    if language == Language::En {
+        return text.to_lowercase();
+    }
+
+    let mut lower = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'I' => lower.push('ı'),
+            'İ' => lower.push('i'),
+            _ => lower.extend(c.to_lowercase()),
+        }
+    }
+
+    lower
+}
+
+/// Key used to detect duplicate words within a category
+///
+/// Case-insensitive with the language's rules. Turkish also ignores the
+/// circumflex (KÂĞIT and KAĞIT are the same word).
+pub fn collation_key(word: &str, language: Language) -> String {
+    let lower = to_lower(&normalize(word), language);
+    if language == Language::En {
+        return lower;
+    }
+
+    lower
+        .chars()
+        .map(|c| match c {
+            'â' => 'a',
+            'î' => 'i',
+            'û' => 'u',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Number of letters (grapheme clusters) in a word
+pub fn letter_count(word: &str) -> i32 {
+    i32::try_from(word.graphemes(true).count()).unwrap_or(i32::MAX)
+}
+
+/// Letters (grapheme clusters) of a word
+pub fn letters(word: &str) -> Vec<String> {
+    word.graphemes(true).map(str::to_string).collect()
+}
+
+/// Order in which the letters of a word are revealed
+///
+/// Letters common in the language come first, as they give away the least;
+/// letters outside the frequency table come last. Equal letters are revealed
+/// left to right.
+pub fn reveal_order(word: &str, language: Language) -> Vec<usize> {
+    let frequency: Vec<&str> = match language {
+        Language::Tr => TURKISH_FREQUENCY,
+        Language::En => ENGLISH_FREQUENCY,
+    }
+    .graphemes(true)
+    .collect();
+
+    let mut order: Vec<(usize, usize)> = word
+        .graphemes(true)
+        .enumerate()
+        .map(|(index, letter)| {
+            let rank = frequency
+                .iter()
+                .position(|&common| common == letter)
+                .unwrap_or(frequency.len());
+            (rank, index)
+        })
+        .collect();
+    order.sort_unstable();
+
+    order.into_iter().map(|(_, index)| index).collect()
+}
+
+/// Letters accepted by an alphabet
+///
+/// `custom_letters` is only used by [`Alphabet::Custom`].
+pub fn allowed_letters(alphabet: Alphabet, custom_letters: Option<&str>) -> Vec<&str> {
+    let letters = match alphabet {
+        Alphabet::Turkish => TURKISH_LETTERS,
+        Alphabet::English => ENGLISH_LETTERS,
+        Alphabet::Custom => custom_letters.unwrap_or_default(),
+    };
+
+    letters.graphemes(true).collect()
+}
+
+/// Clean up a custom alphabet entered by the user
+///
+/// Separators (spaces, commas) are dropped, letters are normalized and
+/// upper-cased with the language's rules and duplicates removed.
+pub fn parse_custom_letters(text: &str, language: Language) -> Result<String, AppError> {
+    let upper = to_upper(&normalize(text), language);
+
+    let mut letters: Vec<&str> = Vec::new();
+    for letter in upper.graphemes(true) {
+        if letter.chars().all(|c| c.is_whitespace() || c == ',') {
+            continue;
+        }
+        if !letter.chars().next().is_some_and(char::is_alphabetic) {
+            return Err(AppError::ValidationError(format!(
+                "Özel alfabe yalnızca harflerden oluşmalıdır (geçersiz: {})",
+                letter
+            )));
+        }
+        if !letters.contains(&letter) {
+            letters.push(letter);
+        }
+    }
+
+    if letters.is_empty() {
+        return Err(AppError::ValidationError(
+            "Özel alfabe boş olamaz".to_string(),
+        ));
+    }
+
+    Ok(letters.concat())
+}
+
+/// Check that every letter of an upper-cased word belongs to the alphabet
+pub fn check_letters(
+    word: &str,
+    alphabet: Alphabet,
+    custom_letters: Option<&str>,
+) -> Result<(), AppError> {
+    let allowed = allowed_letters(alphabet, custom_letters);
+
+    let mut invalid: Vec<&str> = Vec::new();
+    for letter in word.graphemes(true) {
+        if !allowed.contains(&letter) && !invalid.contains(&letter) {
+            invalid.push(letter);
+        }
+    }
+
+    if invalid.is_empty() {
+        return Ok(());
+    }
+
+    Err(AppError::ValidationError(format!(
+        "Kelimede kategorinin alfabesinde olmayan harfler var: {}",
+        invalid.join(", ")
+    )))
+}
+
+/// Language and alphabet that accept a list of words
+///
+/// Used when words arrive without a category to describe them. Turkish wins
+/// over English; words fitting neither get a Turkish category with a custom
+/// alphabet of the Turkish letters plus the extra letters they use.
+pub fn infer_alphabet(words: &[&str]) -> (Language, Alphabet, Option<String>) {
+    let fits = |language, alphabet| {
+        words.iter().all(|word| {
+            let word = to_upper(&normalize(word), language);
+            check_letters(&word, alphabet, None).is_ok()
+        })
+    };
+
+    if fits(Language::Tr, Alphabet::Turkish) {
+        return (Language::Tr, Alphabet::Turkish, None);
+    }
+    if fits(Language::En, Alphabet::English) {
+        return (Language::En, Alphabet::English, None);
+    }
+
+    let mut letters = TURKISH_LETTERS.to_string();
+    letters.extend(words.iter().map(|word| normalize(word)));
+    let letters = parse_custom_letters(&letters, Language::Tr)
+        .unwrap_or_else(|_| TURKISH_LETTERS.to_string());
+
+    (Language::Tr, Alphabet::Custom, Some(letters))
+}
+
+/// Check of [`check_word`] that a word failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordProblem {
+    /// Nothing left after trimming
+    Empty,
+    /// Letters outside the category's alphabet
+    InvalidLetters,
+    /// Letter count outside the supported range
+    BadLength,
+}
+
+/// Normalize, upper-case and validate a word for a category
+///
+/// Returns the stored form of the word and its letter count.
+pub fn prepare_word(word: &str, rules: &WordRules<'_>) -> Result<(String, i32), AppError> {
+    check_word(word, rules).map_err(|(_, error)| error)
+}
+
+/// [`prepare_word`] that also reports which check failed
+pub fn check_word(
+    word: &str,
+    rules: &WordRules<'_>,
+) -> Result<(String, i32), (WordProblem, AppError)> {
+    let word = to_upper(&normalize(word), rules.language);
+    if word.is_empty() {
+        return Err((
+            WordProblem::Empty,
+            AppError::ValidationError("Kelime boş olamaz".to_string()),
+        ));
+    }
+
+    check_letters(&word, rules.alphabet, rules.custom_letters)
+        .map_err(|error| (WordProblem::InvalidLetters, error))?;
+
+    let letter_count = letter_count(&word);
+    validate_word_length(letter_count).map_err(|error| (WordProblem::BadLength, error))?;
+
+    Ok((word, letter_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TURKISH: WordRules<'static> = WordRules {
+        language: Language::Tr,
+        alphabet: Alphabet::Turkish,
+        custom_letters: None,
+    };
+
+    const ENGLISH: WordRules<'static> = WordRules {
+        language: Language::En,
+        alphabet: Alphabet::English,
+        custom_letters: None,
+    };
+
+    #[test]
+    fn turkish_casing_keeps_dotted_and_dotless_i_apart() {
+        assert_eq!(to_upper("istanbul", Language::Tr), "İSTANBUL");
+        assert_eq!(to_upper("ılık", Language::Tr), "ILIK");
+        assert_eq!(to_upper("şişe", Language::Tr), "ŞİŞE");
+        assert_eq!(to_upper("quiz", Language::En), "QUIZ");
+        assert_eq!(to_lower("IŞIK İNCİ", Language::Tr), "ışık inci");
+        assert_eq!(to_lower("QUIZ", Language::En), "quiz");
+    }
+
+    #[test]
+    fn decomposed_letters_are_composed_and_counted_once() {
+        // "ŞEKER" typed with S + combining cedilla
+        let (word, count) = prepare_word("s\u{327}eker", &TURKISH).unwrap();
+        assert_eq!(word, "ŞEKER");
+        assert_eq!(count, 5);
+
+        // "i" + combining dot above
+        let (word, _) = prepare_word("i\u{307}nci", &TURKISH).unwrap();
+        assert_eq!(word, "İNCİ");
+    }
+
+    #[test]
+    fn words_are_checked_against_the_alphabet() {
+        assert!(prepare_word("çiçek", &TURKISH).is_ok());
+        assert!(prepare_word("watch", &TURKISH).is_err());
+        assert!(prepare_word("watch", &ENGLISH).is_ok());
+        assert!(prepare_word("çiçek", &ENGLISH).is_err());
+        assert!(prepare_word("iki kelime", &TURKISH).is_err());
+
+        let custom = parse_custom_letters("a, b, c, ä", Language::Tr).unwrap();
+        assert_eq!(custom, "ABCÄ");
+        let rules = WordRules {
+            language: Language::Tr,
+            alphabet: Alphabet::Custom,
+            custom_letters: Some(&custom),
+        };
+        assert!(prepare_word("abä", &rules).is_ok());
+        assert!(prepare_word("abd", &rules).is_err());
+        assert!(parse_custom_letters(" , ", Language::Tr).is_err());
+        assert!(parse_custom_letters("a1", Language::Tr).is_err());
+    }
+
+    #[test]
+    fn alphabet_is_inferred_from_the_words() {
+        assert_eq!(
+            infer_alphabet(&["çiçek", "kitap"]),
+            (Language::Tr, Alphabet::Turkish, None)
+        );
+        assert_eq!(
+            infer_alphabet(&["quiz", "watch"]),
+            (Language::En, Alphabet::English, None)
+        );
+
+        let (language, alphabet, letters) = infer_alphabet(&["çiçek", "quiz"]);
+        assert_eq!((language, alphabet), (Language::Tr, Alphabet::Custom));
+        assert!(letters.unwrap().ends_with("ÛQ"));
+    }
+
+    #[test]
+    fn failed_checks_are_reported() {
+        let problem = |word| check_word(word, &TURKISH).err().map(|(problem, _)| problem);
+        assert_eq!(problem(" elma "), None);
+        assert_eq!(problem("  "), Some(WordProblem::Empty));
+        assert_eq!(problem("x"), Some(WordProblem::InvalidLetters));
+        assert_eq!(problem("a"), Some(WordProblem::BadLength));
+        assert_eq!(problem("abcçdefgğhıijklm"), Some(WordProblem::BadLength));
+    }
+
+    #[test]
+    fn collation_follows_the_language() {
+        assert_eq!(
+            collation_key("KÂĞIT", Language::Tr),
+            collation_key("kağıt", Language::Tr)
+        );
+        assert_ne!(
+            collation_key("KIR", Language::Tr),
+            collation_key("kir", Language::Tr)
+        );
+        assert_eq!(
+            collation_key("KIR", Language::En),
+            collation_key("kir", Language::En)
+        );
+    }
+
+    #[test]
+    fn common_letters_are_revealed_first() {
+        assert_eq!(reveal_order("ÇAY", Language::Tr), vec![1, 2, 0]);
+        assert_eq!(reveal_order("QUEEN", Language::En), vec![2, 3, 4, 1, 0]);
+    }
+}
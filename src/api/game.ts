@@ -0,0 +1,219 @@
+/**
+ * Game Session API - Tauri Command Wrappers
+ * PRD Reference: Section 4.5 & 4.6 - Game Screen
+ *
+ * The backend owns the running game: scoring and timers are applied there
+ * and finished games are saved from its state.
+ */
+import { invoke } from '@tauri-apps/api/core';
+import type { GameMode, GameSession, GameState, ParticipantType, WordResult } from '../types/game';
+
+// ===== Types =====
+
+export type GamePhase = 'playing' | 'guessing' | 'word_resolved' | 'waiting_next_turn' | 'finished';
+
+export interface SessionWord {
+  id: number;
+  word: string;
+  hint: string;
+  letter_count: number;
+  letters: string[];
+  revealed: boolean[];
+  letters_revealed: number;
+  has_made_guess: boolean;
+  result: WordResult | null;
+  points_earned: number;
+}
+
+export interface SessionParticipant {
+  name: string;
+  participant_type: ParticipantType;
+  score: number;
+  words_found: number;
+  words_wrong: number;
+  words_skipped: number;
+  letters_revealed: number;
+  current_word_index: number;
+  words: SessionWord[];
+  elapsed_time_seconds: number;
+  time_bonus: number;
+}
+
+export interface BackendGameSession {
+  id: string;
+  category_id: number;
+  category_name: string;
+  category_emoji: string;
+  mode: GameMode;
+  phase: GamePhase;
+  is_paused: boolean;
+  participants: SessionParticipant[];
+  active_participant_index: number;
+  total_time_seconds: number;
+  guess_time_seconds: number;
+  guess_time_remaining: number;
+  started_at: string;
+}
+
+export interface GameSetup {
+  category_id: number;
+  mode: GameMode;
+  participants: string[];
+  game_duration_seconds?: number;
+  guess_duration_seconds?: number;
+}
+
+// ===== API Functions =====
+
+/**
+ * Start a new game; the backend selects the words
+ */
+export async function startGameSession(setup: GameSetup): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('start_game_session', { setup });
+}
+
+/**
+ * Get the active game with its timers up to date
+ */
+export async function getGameSession(): Promise<BackendGameSession | null> {
+  return invoke<BackendGameSession | null>('get_game_session');
+}
+
+/**
+ * Reveal the next letter of the current word
+ */
+export async function revealLetter(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('reveal_letter');
+}
+
+/**
+ * Enter guess mode
+ */
+export async function startGuess(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('start_guess');
+}
+
+/**
+ * Resolve the current guess as correct or wrong
+ */
+export async function submitGuess(isCorrect: boolean): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('submit_guess', { isCorrect });
+}
+
+/**
+ * Continue with the next word after a result
+ */
+export async function nextWord(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('next_word');
+}
+
+/**
+ * Start the next participant's turn
+ */
+export async function nextParticipant(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('next_participant');
+}
+
+/**
+ * Pause all timers
+ */
+export async function pauseGame(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('pause_game');
+}
+
+/**
+ * Resume the timers
+ */
+export async function resumeGame(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('resume_game');
+}
+
+/**
+ * End the game early
+ */
+export async function endGame(): Promise<BackendGameSession> {
+  return invoke<BackendGameSession>('end_game');
+}
+
+/**
+ * Save the finished game to history
+ * Results are computed by the backend; returns the game history ID
+ */
+export async function finishGameSession(): Promise<number> {
+  return invoke<number>('finish_game_session');
+}
+
+/**
+ * Discard the active game without saving it
+ */
+export async function abandonGameSession(): Promise<void> {
+  return invoke<void>('abandon_game_session');
+}
+
+// ===== Helper Functions =====
+
+/**
+ * Convert the backend session to the shape used by the game screens
+ *
+ * @param isInTransition - UI transition state, kept by the frontend
+ */
+export function toGameSession(session: BackendGameSession, isInTransition: boolean): GameSession {
+  const isFinished = session.phase === 'finished';
+  const state: GameState = isFinished
+    ? 'finished'
+    : session.phase === 'waiting_next_turn'
+      ? 'waiting_next_turn'
+      : session.is_paused
+        ? 'paused'
+        : 'playing';
+
+  return {
+    id: session.id,
+    categoryId: session.category_id,
+    categoryName: session.category_name,
+    categoryEmoji: session.category_emoji,
+    mode: session.mode,
+    state,
+    participants: session.participants.map((participant, index) => ({
+      name: participant.name,
+      type: participant.participant_type,
+      score: participant.score,
+      wordsFound: participant.words_found,
+      wordsWrong: participant.words_wrong,
+      wordsSkipped: participant.words_skipped,
+      lettersRevealed: participant.letters_revealed,
+      currentWordIndex: participant.current_word_index,
+      words: participant.words.map((word) => ({
+        id: word.id,
+        word: word.word,
+        hint: word.hint,
+        letterCount: word.letter_count,
+        letters: word.letters.map((char, letterIndex) => ({
+          char,
+          index: letterIndex,
+          status: word.revealed[letterIndex] ? ('revealed' as const) : ('hidden' as const),
+        })),
+        lettersRevealed: word.letters_revealed,
+        hasMadeGuess: word.has_made_guess,
+        result: word.result,
+        pointsEarned: word.points_earned,
+      })),
+      isActive: index === session.active_participant_index && state === 'playing',
+      elapsedTimeSeconds: participant.elapsed_time_seconds,
+      totalTimeSeconds: session.total_time_seconds,
+    })),
+    activeParticipantIndex: session.active_participant_index,
+    totalTimeSeconds: session.total_time_seconds,
+    elapsedTimeSeconds: session.participants.reduce(
+      (sum, participant) => sum + participant.elapsed_time_seconds,
+      0
+    ),
+    isPaused: session.is_paused,
+    isInTransition,
+    isGuessing: session.phase === 'guessing',
+    isWordResolved: session.phase === 'word_resolved',
+    guessTimeRemaining: session.guess_time_remaining,
+    startedAt: session.started_at,
+    finishedAt: null,
+  };
+}
@@ -48,40 +48,11 @@ export interface GameHistoryStats {
   total_play_time_seconds: number;
 }
 
-export interface GameSessionData {
-  category_id: number;
-  category_name: string;
-  game_mode: string;
-  played_at: string;
-  total_time_seconds?: number;
-  participants: ParticipantData[];
-}
-
-export interface ParticipantData {
-  name: string;
-  participant_type: string;
-  score: number;
-  words_found: number;
-  words_wrong: number;
-  words_skipped: number;
-  letters_revealed: number;
-  elapsed_time_seconds?: number;
-  rank?: number;
-  word_results: WordResultData[];
-}
-
-export interface WordResultData {
-  word: string;
-  word_hint?: string;
-  result: string;
-  points_earned: number;
-  letters_used: number;
-}
-
 // ===== Query Options =====
 
 export interface GameHistoryQueryOptions {
   category_id?: number;
+  language?: string;
   game_mode?: string;
   start_date?: string; // ISO date string
   end_date?: string; // ISO date string
@@ -99,17 +70,7 @@ export interface GameHistoryQueryOptions {
 export async function getAllGameHistory(
   options: GameHistoryQueryOptions = {}
 ): Promise<GameHistory[]> {
-  // Convert options to Record<string, unknown> for Tauri invoke
-  const invokeOptions: Record<string, unknown> = {};
-  if (options.category_id !== undefined) invokeOptions.categoryId = options.category_id;
-  if (options.game_mode !== undefined) invokeOptions.gameMode = options.game_mode;
-  if (options.start_date !== undefined) invokeOptions.startDate = options.start_date;
-  if (options.end_date !== undefined) invokeOptions.endDate = options.end_date;
-  if (options.sort_by !== undefined) invokeOptions.sortBy = options.sort_by;
-  if (options.limit !== undefined) invokeOptions.limit = options.limit;
-  if (options.offset !== undefined) invokeOptions.offset = options.offset;
-
-  return invoke<GameHistory[]>('get_all_game_history', invokeOptions);
+  return invoke<GameHistory[]>('get_all_game_history', { filter: options });
 }
 
 /**
@@ -165,14 +126,6 @@ export async function deleteAllGameHistory(): Promise<void> {
   return invoke<void>('delete_all_game_history');
 }
 
-/**
- * Save a complete game session to history
- * This is called after a game ends to persist the results
- */
-export async function saveGameToHistory(gameData: GameSessionData): Promise<number> {
-  return invoke<number>('save_game_to_history', { session: gameData });
-}
-
 // ===== Helper Functions =====
 
 /**
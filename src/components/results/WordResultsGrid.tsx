@@ -95,7 +95,8 @@ export default function WordResultsGrid({ words }: WordResultsGridProps) {
             word.result === 'found' ? '✅' : 
             word.result === 'wrong' ? '❌' :
             word.result === 'timeout' ? '⏰' :
-            word.result === 'skipped' ? '⏭️' : '❓';
+            word.result === 'skipped' ? '⏭️' :
+            word.result === 'unplayed' ? '⌛' : '❓';
 
           // Status-based styling
           let borderColor = 'border-neutral-700';
@@ -116,6 +117,8 @@ export default function WordResultsGrid({ words }: WordResultsGridProps) {
             borderColor = 'border-error-500';
             bgColor = 'bg-error-500/10';
             opacity = 'opacity-60';
+          } else if (word.result === 'unplayed') {
+            opacity = 'opacity-40';
           }
 
           return (
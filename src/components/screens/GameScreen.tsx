@@ -5,6 +5,8 @@
  * 1. Normal Mode: Reveal letters, press "Tahmin Et" to enter guess mode
  * 2. Guess Mode: 30-second timer, host marks correct/wrong
  *
+ * The game runs in the backend: timers and scores shown here come from it.
+ *
  * Features:
  * - Header: Timer (global or guess), score, active player
  * - Word Area: Letter tiles with animations
@@ -36,34 +38,27 @@ import { Button } from '../ui/Button';
 export const GameScreen: React.FC = () => {
   const navigate = useNavigate();
   const session = useGameStore((state) => state.session);
-  const tick = useGameStore((state) => state.tick);
+  const syncGame = useGameStore((state) => state.syncGame);
   const pauseGame = useGameStore((state) => state.pauseGame);
   const resumeGame = useGameStore((state) => state.resumeGame);
   const revealLetter = useGameStore((state) => state.revealLetter);
   const submitGuess = useGameStore((state) => state.submitGuess);
   const nextWord = useGameStore((state) => state.nextWord);
   const setTransition = useGameStore((state) => state.setTransition);
-  const endGame = useGameStore((state) => state.endGame);
+  const abandonGame = useGameStore((state) => state.abandonGame);
   
   // Guess mode actions
   const startGuess = useGameStore((state) => state.startGuess);
-  const guessTimerTick = useGameStore((state) => state.guessTimerTick);
-  const handleGuessTimeout = useGameStore((state) => state.handleGuessTimeout);
-  const endGuessMode = useGameStore((state) => state.endGuessMode);
 
   // Settings
   const soundEnabled = useSettingsStore((state) => state.soundEnabled);
   const showGameButtons = useSettingsStore((state) => state.showGameButtons);
-  const guessTimerDuration = useSettingsStore((state) => state.guessTimerDuration);
 
   const [showConfetti, setShowConfetti] = useState(false);
   const [showHomeModal, setShowHomeModal] = useState(false);
   const [showIncorrect, setShowIncorrect] = useState(false);
   const [showTimeout, setShowTimeout] = useState(false);
   
-  // Ref to track if timeout was just handled (prevent double execution)
-  const timeoutHandledRef = useRef(false);
-  
   // Ref to store current session for use in intervals (avoids stale closures)
   const sessionRef = useRef(session);
   useEffect(() => {
@@ -98,7 +93,7 @@ export const GameScreen: React.FC = () => {
   }, [session?.participants[session?.activeParticipantIndex]?.currentWordIndex]);
 
   // Timer tick effect - Global timer (only when not in guess mode)
-  // Uses specific session properties to avoid unnecessary re-renders
+  // The backend advances the timers; each tick fetches their current values
   const isPaused = session?.isPaused ?? true;
   const gameState = session?.state ?? 'setup';
   const isGuessing = session?.isGuessing ?? false;
@@ -112,7 +107,7 @@ export const GameScreen: React.FC = () => {
       const currentSession = sessionRef.current;
       if (!currentSession || currentSession.isGuessing || isInTransitionRef.current) return;
       
-      tick();
+      syncGame();
 
       // Use ref for fresh session data to avoid stale closure
       if (currentSession) {
@@ -126,11 +121,9 @@ export const GameScreen: React.FC = () => {
     }, 1000);
 
     return () => clearInterval(interval);
-  }, [isPaused, gameState, isGuessing, tick, session]);
+  }, [isPaused, gameState, isGuessing, syncGame, session]);
 
   // Guess timer tick effect - Only when in guess mode
-  const guessTimeRemaining = session?.guessTimeRemaining ?? 0;
-  
   useEffect(() => {
     if (!session || isPaused || !isGuessing || isInTransition) return;
 
@@ -151,57 +144,34 @@ export const GameScreen: React.FC = () => {
         soundService.playTick();
       }
       
-      guessTimerTick();
+      syncGame();
     }, 1000);
 
     return () => clearInterval(interval);
-  }, [isGuessing, isPaused, isInTransition, guessTimerTick, session]);
+  }, [isGuessing, isPaused, isInTransition, syncGame, session]);
 
-  // Handle guess timeout - when timer reaches 0
+  // Words resolved by the backend rather than a guess button:
+  // guess timer expired (timeout) or all letters revealed (skipped)
   useEffect(() => {
-    if (!session || !isGuessing || guessTimeRemaining > 0) {
-      timeoutHandledRef.current = false;
-      return;
-    }
-    
-    // Prevent double execution
-    if (timeoutHandledRef.current) return;
-    timeoutHandledRef.current = true;
-
-    // Timer reached 0 - handle timeout
-    handleGuessTimeout();
-    soundService.playError();
-    setShowTimeout(true);
+    if (!session || !session.isWordResolved || session.isInTransition) return;
+
+    const activeParticipant = session.participants[session.activeParticipantIndex];
+    const currentWord = activeParticipant?.words[activeParticipant.currentWordIndex];
+    if (!currentWord) return;
+
     setTransition(true);
 
-    // Show timeout overlay, then move to next word
-    setTimeout(() => {
+    if (currentWord.result === 'timeout') {
+      soundService.playError();
+      setShowTimeout(true);
+    }
+
+    // Show the revealed word (or timeout overlay), then move to next word
+    setTimeout(async () => {
       setShowTimeout(false);
+      await nextWord();
       setTransition(false);
-      nextWord();
     }, 2000);
-  }, [isGuessing, guessTimeRemaining, handleGuessTimeout, nextWord, setTransition]);
-
-  // Check if all letters revealed - auto skip
-  useEffect(() => {
-    if (!session) return;
-    
-    const activeParticipant = session.participants[session.activeParticipantIndex];
-    const currentWord = activeParticipant?.words[activeParticipant.currentWordIndex];
-    
-    if (!currentWord || currentWord.result !== null || session.isGuessing || session.isInTransition) return;
-    
-    // Check if all letters are revealed
-    const allRevealed = currentWord.letters.every(l => l.status === 'revealed');
-    
-    if (allRevealed && currentWord.result === 'skipped') {
-      // Word was auto-skipped by store, transition to next
-      setTransition(true);
-      setTimeout(() => {
-        setTransition(false);
-        nextWord();
-      }, 2000);
-    }
   }, [session, nextWord, setTransition]);
 
   // Store refs for handlers to use in keyboard shortcuts (avoids stale closures)
@@ -332,20 +302,15 @@ export const GameScreen: React.FC = () => {
 
   // Handlers
   const handleRevealLetter = () => {
-    if (!session || session.isGuessing) return;
+    if (!session || session.isGuessing || session.isWordResolved) return;
     
     const activeParticipant = session.participants[session.activeParticipantIndex];
     const currentWord = activeParticipant.words[activeParticipant.currentWordIndex];
     
-    // Find all hidden letters
-    const hiddenIndices = currentWord.letters
-      .map((letter, index) => (letter.status === 'hidden' ? index : -1))
-      .filter((index) => index !== -1);
-
-    // Pick a random hidden letter
-    if (hiddenIndices.length > 0 && !currentWord.hasMadeGuess) {
-      const randomIndex = hiddenIndices[Math.floor(Math.random() * hiddenIndices.length)];
-      revealLetter(session.activeParticipantIndex, activeParticipant.currentWordIndex, randomIndex);
+    // The backend picks the letter (common letters of the language first)
+    const hasHiddenLetter = currentWord.letters.some((letter) => letter.status === 'hidden');
+    if (hasHiddenLetter && !currentWord.hasMadeGuess) {
+      revealLetter();
       soundService.playPop();
     }
   };
@@ -358,7 +323,7 @@ export const GameScreen: React.FC = () => {
     
     if (currentWord.hasMadeGuess || currentWord.result !== null) return;
     
-    startGuess(guessTimerDuration);
+    startGuess();
     soundService.playClick(); // Guess mode entry sound
   };
 
@@ -370,21 +335,20 @@ export const GameScreen: React.FC = () => {
 
     if (!currentWord || currentWord.result !== null) return;
 
-    // End guess mode first
-    endGuessMode();
-    
-    // Submit the guess
-    submitGuess(session.activeParticipantIndex, activeParticipant.currentWordIndex, isCorrect);
+    // Enter the transition before the result arrives
+    setTransition(true);
+
+    // Submit the guess (scored by the backend)
+    submitGuess(isCorrect);
 
     if (isCorrect) {
       // Correct answer: Show confetti, wait, move to next word
       setShowConfetti(true);
-      setTransition(true);
       soundService.playSuccess();
 
-      setTimeout(() => {
+      setTimeout(async () => {
+        await nextWord();
         setTransition(false);
-        nextWord();
       }, 3000);
     } else {
       // Wrong answer: Show animation, reveal word, move to next
@@ -395,10 +359,9 @@ export const GameScreen: React.FC = () => {
         setShowIncorrect(false);
       }, 600);
 
-      setTransition(true);
-      setTimeout(() => {
+      setTimeout(async () => {
+        await nextWord();
         setTransition(false);
-        nextWord();
       }, 2000);
     }
   };
@@ -420,7 +383,7 @@ export const GameScreen: React.FC = () => {
   };
 
   const confirmHome = () => {
-    endGame();
+    abandonGame();
     navigate('/');
   };
 
@@ -615,7 +578,7 @@ export const GameScreen: React.FC = () => {
                 {/* Score penalty */}
                 <div className="mt-2 px-6 py-3 bg-error-500/20 rounded-xl border border-error-500/30">
                   <p className="text-xl md:text-2xl font-bold text-error-400 tabular-nums">
-                    -{currentWord.letterCount * 100} puan
+                    {currentWord.pointsEarned} puan
                   </p>
                 </div>
                 
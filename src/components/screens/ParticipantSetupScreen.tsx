@@ -20,7 +20,6 @@ import { Button } from '../ui/Button';
 import { useCategoryStore } from '../../store/categoryStore';
 import { useGameStore } from '../../store/gameStore';
 import { useSettingsStore } from '../../store/settingsStore';
-import { selectWordsForGame } from '../../services';
 import { ROUTES } from '../../routes/constants';
 import { SinglePlayerForm } from '../forms/SinglePlayerForm';
 import { MultiPlayerForm } from '../forms/MultiPlayerForm';
@@ -155,40 +154,6 @@ export function ParticipantSetupScreen() {
     }
 
     try {
-      // Calculate participant count based on mode
-      const participantCount =
-        selectedMode === 'single'
-          ? 1
-          : selectedMode === 'multi'
-            ? (currentSetup as MultiPlayerSetup).players.length
-            : (currentSetup as TeamModeSetup).teams.length;
-
-      // Select words for the game (Task 13)
-      const wordSets = await selectWordsForGame(
-        selectedCategory.id,
-        selectedMode,
-        participantCount
-      );
-
-      // Transform words to GameWord format for gameStore
-      const gameWords = wordSets.map((wordSet) =>
-        wordSet.map((word) => ({
-          id: word.id,
-          word: word.word,
-          letterCount: word.letterCount,
-          hint: word.hint,
-          letters: word.word.split('').map((char, index) => ({
-            char,
-            index,
-            status: 'hidden' as const,
-          })),
-          lettersRevealed: 0,
-          hasMadeGuess: false,
-          result: null,
-          pointsEarned: 0,
-        }))
-      );
-
       // Create game config with all required data
       const gameConfig = {
         categoryId: selectedCategory.id,
@@ -197,10 +162,11 @@ export function ParticipantSetupScreen() {
         mode: selectedMode,
         setup: currentSetup,
         gameDuration: useSettingsStore.getState().gameDuration,
+        guessDuration: useSettingsStore.getState().guessTimerDuration,
       };
 
-      // Start game in gameStore
-      startGame(gameConfig, gameWords);
+      // Start game in the backend (words are selected there)
+      await startGame(gameConfig);
 
       // Save setup and navigate to game screen
       setGameSetup(currentSetup);
@@ -19,7 +19,7 @@ import { motion } from 'framer-motion';
 import { BarChart3, Clock, Target, Zap } from 'lucide-react';
 import type { GameSession } from '../../types';
 import { ROUTES } from '../../routes/constants';
-import { saveGameToHistory, type GameSessionData } from '../../api/gameHistory';
+import { finishGameSession } from '../../api/game';
 import CelebrationHero from '../results/CelebrationHero';
 import WordResultsGrid from '../results/WordResultsGrid';
 import { ResultsActions } from '../results/ResultsActions';
@@ -54,35 +54,8 @@ export function ResultsSinglePlayer({ session, onPlayAgain }: ResultsSinglePlaye
     // Mark as saved immediately with timestamp
     markSessionSaved(session.id);
 
-    const gameData: GameSessionData = {
-      category_id: session.categoryId,
-      category_name: session.categoryName,
-      game_mode: session.mode,
-      played_at: new Date().toISOString(),
-      // Use participant's elapsed time, not session's (which is not updated)
-      total_time_seconds: participant.elapsedTimeSeconds,
-      participants: [
-        {
-          name: participant.name,
-          participant_type: participant.type,
-          score: participant.score,
-          words_found: participant.wordsFound,
-          words_wrong: participant.wordsWrong,
-          words_skipped: participant.wordsSkipped,
-          letters_revealed: participant.lettersRevealed,
-          rank: 1,
-          word_results: participant.words.map((word) => ({
-            word: word.word,
-            word_hint: word.hint,
-            result: word.result || 'skipped',
-            points_earned: word.pointsEarned,
-            letters_used: word.lettersRevealed,
-          })),
-        },
-      ],
-    };
-
-    saveGameToHistory(gameData)
+    // Results are computed from the backend's game state
+    finishGameSession()
       .catch((err) => {
         console.error('❌ Failed to save game to history:', err);
         // Remove from set on error so it can be retried
@@ -21,7 +21,7 @@ import { ChevronDown, Users, BarChart3, Clock, Target, Zap } from 'lucide-react'
 import type { GameSession, Team } from '../../types';
 import { TeamChip } from '../ui/TeamChip';
 import { ROUTES } from '../../routes/constants';
-import { saveGameToHistory, type GameSessionData } from '../../api/gameHistory';
+import { finishGameSession } from '../../api/game';
 import CelebrationHero from '../results/CelebrationHero';
 import PodiumDisplay from '../results/PodiumDisplay';
 import WordResultsGrid from '../results/WordResultsGrid';
@@ -97,40 +97,8 @@ export function ResultsTeamMode({ session, teams, onPlayAgain }: ResultsTeamMode
     // Mark as saved immediately
     markSessionSaved(session.id);
 
-    // Calculate total elapsed time from all teams
-    const totalElapsedTime = participantsWithRank.reduce(
-      (sum, p) => sum + p.elapsedTimeSeconds,
-      0
-    );
-
-    const gameData: GameSessionData = {
-      category_id: session.categoryId,
-      category_name: session.categoryName,
-      game_mode: session.mode,
-      played_at: new Date().toISOString(),
-      // Use sum of all teams' elapsed time
-      total_time_seconds: totalElapsedTime,
-      participants: participantsWithRank.map((p) => ({
-        name: p.name,
-        participant_type: p.type,
-        score: p.score,
-        words_found: p.wordsFound,
-        words_wrong: p.wordsWrong,
-        words_skipped: p.wordsSkipped,
-        letters_revealed: p.lettersRevealed,
-        elapsed_time_seconds: p.elapsedTimeSeconds,
-        rank: p.rank,
-        word_results: p.words.map((word) => ({
-          word: word.word,
-          word_hint: word.hint,
-          result: word.result || 'skipped',
-          points_earned: word.pointsEarned,
-          letters_used: word.lettersRevealed,
-        })),
-      })),
-    };
-
-    saveGameToHistory(gameData)
+    // Results are computed from the backend's game state
+    finishGameSession()
       .catch((err) => {
         console.error('❌ Failed to save game to history:', err);
         // Remove from set on error so it can be retried
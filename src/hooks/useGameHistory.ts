@@ -69,8 +69,10 @@ export function useGameHistory(): UseGameHistoryResult {
     try {
       // Get all game history entries
       const allGames = await invoke<GameHistory[]>('get_all_game_history', {
-        limit: 1000, // Get all games for stats
-        offset: 0,
+        filter: {
+          limit: 1000, // Get all games for stats
+          offset: 0,
+        },
       });
 
       if (allGames.length === 0) {
@@ -173,9 +175,11 @@ export function useGameHistory(): UseGameHistoryResult {
     try {
       // Get the most recent game (limit 1)
       const recentGames = await invoke<GameHistory[]>('get_all_game_history', {
-        sortBy: 'date_desc',
-        limit: 1,
-        offset: 0,
+        filter: {
+          sort_by: 'date_desc',
+          limit: 1,
+          offset: 0,
+        },
       });
 
       if (recentGames.length === 0) {
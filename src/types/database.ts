@@ -93,7 +93,7 @@ export interface GameWordResult {
   participant_id: number;
   word: string;
   word_hint: string | null;
-  result: 'found' | 'wrong' | 'skipped' | 'timeout';
+  result: 'found' | 'wrong' | 'skipped' | 'timeout' | 'unplayed';
   points_earned: number;
   letters_used: number;
   created_at: string;
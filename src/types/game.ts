@@ -32,8 +32,9 @@ export type LetterStatus = 'hidden' | 'revealed';
  * - wrong: Incorrect guess (penalty applied)
  * - skipped: All letters revealed without guessing
  * - timeout: Guess timer expired
+ * - unplayed: Participant ran out of time before reaching the word
  */
-export type WordResult = 'found' | 'wrong' | 'skipped' | 'timeout';
+export type WordResult = 'found' | 'wrong' | 'skipped' | 'timeout' | 'unplayed';
 
 /**
  * Single player setup
@@ -148,6 +149,7 @@ export interface GameSession {
   // Guess mode state
   isGuessing: boolean; // True when in guess mode (global timer paused)
   guessTimeRemaining: number; // Countdown timer for guess mode
+  isWordResolved: boolean; // Current word has a result, waiting for the next word
 
   // Timestamps
   startedAt: string | null;
@@ -164,6 +166,7 @@ export interface GameConfig {
   mode: GameMode;
   setup: SinglePlayerSetup | MultiPlayerSetup | TeamModeSetup;
   gameDuration: number; // Game duration in seconds (passed from settings)
+  guessDuration: number; // Guess timer in seconds (passed from settings)
 }
 
 /**